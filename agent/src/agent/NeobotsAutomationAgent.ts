@@ -190,15 +190,14 @@ export class NeobotsAutomationAgent {
       const user = await this.operator.getUser();
 
       session.setMessage("Uploading post to the storage... 📂", "running");
-      const contentUri = await this.storage.put(
-        JSON.stringify({
-          title: post.title,
-          content: post.content,
-          voteTitle: post.voteTitle,
-          voteOptions: post.voteOptions,
-          enableVoting: post.enableVoting,
-        })
-      );
+      const postBody = JSON.stringify({
+        title: post.title,
+        content: post.content,
+        voteTitle: post.voteTitle,
+        voteOptions: post.voteOptions,
+        enableVoting: post.enableVoting,
+      });
+      const contentUri = await this.storage.put(postBody);
       session.setMessage("Post uploaded to the storage.", "success");
 
       session.setMessage("Sending post to Chain... 🔗", "running");
@@ -206,7 +205,8 @@ export class NeobotsAutomationAgent {
         const sig = await this.operator.getProgramService().createPost(
           user.nftMint,
           contentUri,
-          "general" // TODO: use the category from the LLM
+          "general", // TODO: use the category from the LLM
+          postBody
         );
         session.setMessage("Post created on chain. 🔗", "success");
         console.log("Created post (sig):", sig);
@@ -350,16 +350,16 @@ export class NeobotsAutomationAgent {
         // 5) post on chain
         if (!this.config.dryRun) {
           const user = await this.operator.getUser();
+          const commentBody = JSON.stringify({
+            content: newComment.content,
+            voteTo: newComment.voteTo,
+          });
           const sig = await this.operator.getProgramService().addComment(
             user.nftMint,
             fullPost.post_sequence_id, // or however your chain code expects the post ID
             new PublicKey(fullPost.post_author_pda),
-            await this.storage.put(
-              JSON.stringify({
-                content: newComment.content,
-                voteTo: newComment.voteTo,
-              })
-            )
+            await this.storage.put(commentBody),
+            commentBody
           );
           session.setMessage("Comment posted! sig:" + sig);
           console.log("Added comment (sig): ", sig);
@@ -5,30 +5,41 @@
  *
  * @param allLogs - The entire array of log messages from the transaction
  * @param programId - The program ID string for which we're filtering logs
- * @returns An object { logsForThisIx, leftoverLogs } so we can remove consumed lines from the original array
+ * @returns An object { logsForThisIx, eventsForThisIx, leftoverLogs } so we can remove consumed lines from the original array.
+ *          `eventsForThisIx` holds the base64 payloads of the Anchor events (`emit!`) of this instruction.
  */
 export function extractProgramLogs(
   allLogs: string[],
   programId: string
-): { logsForThisIx: string[]; leftoverLogs: string[] } {
+): {
+  logsForThisIx: string[];
+  eventsForThisIx: string[];
+  leftoverLogs: string[];
+} {
   const programStartQuery = `Program ${programId} invoke`;
   const programEndQuery = `Program ${programId} consumed`;
   const programLogPrefix = "Program log:";
+  const programDataPrefix = "Program data:";
 
   let idx = allLogs.findIndex((l) => l.startsWith(programStartQuery));
   if (idx < 0) {
     // no logs found for this instruction
-    return { logsForThisIx: [], leftoverLogs: allLogs };
+    return { logsForThisIx: [], eventsForThisIx: [], leftoverLogs: allLogs };
   }
 
   // Move one past the "Program ... invoke" line
   idx++;
   const logsForThisIx: string[] = [];
+  const eventsForThisIx: string[] = [];
   while (idx < allLogs.length && !allLogs[idx].startsWith(programEndQuery)) {
     if (allLogs[idx].startsWith(programLogPrefix)) {
       // remove "Program log:" part
       const line = allLogs[idx].slice(programLogPrefix.length + 1);
       logsForThisIx.push(line);
+    } else if (allLogs[idx].startsWith(programDataPrefix)) {
+      // remove "Program data:" part, the rest is the base64 encoded event
+      const line = allLogs[idx].slice(programDataPrefix.length + 1);
+      eventsForThisIx.push(line);
     }
     idx++;
   }
@@ -36,5 +47,5 @@ export function extractProgramLogs(
   // leftover logs is everything after we've consumed logs for this instruction
   const leftoverLogs = allLogs.slice(idx);
 
-  return { logsForThisIx, leftoverLogs };
+  return { logsForThisIx, eventsForThisIx, leftoverLogs };
 }
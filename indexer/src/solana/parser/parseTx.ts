@@ -1,10 +1,11 @@
 // parseAny.ts
 
 import { ParsedTransactionWithMeta, PublicKey } from "@solana/web3.js";
-import { BorshInstructionCoder } from "@coral-xyz/anchor";
+import { BorshEventCoder, BorshInstructionCoder } from "@coral-xyz/anchor";
 import { extractProgramLogs } from "./parseLogs";
 import { decodeInstruction } from "./decoder";
 import {
+  decodeEvents,
   parseCreatePost,
   parseAddComment,
   parseAddReaction,
@@ -43,6 +44,7 @@ export function parseAny(params: ParseAnyParams): {
   // We may mutate `remainingLogs` as we go
   let remainingLogs = tx.meta?.logMessages ?? [];
   const coder = new BorshInstructionCoder(idl as any);
+  const eventCoder = new BorshEventCoder(idl as any);
 
   const results: ParsedInstruction[] = [];
   const rewards: RewardData[] = [];
@@ -62,13 +64,15 @@ export function parseAny(params: ParseAnyParams): {
 
     try {
       // 1) Extract logs relevant to *this* instruction
-      const { logsForThisIx, leftoverLogs } = extractProgramLogs(
+      const { eventsForThisIx, leftoverLogs } = extractProgramLogs(
         remainingLogs,
         programId.toString()
       );
       remainingLogs = leftoverLogs; // update the logs we haven't used yet
 
-      const parsedRewards = parseRewards(logsForThisIx);
+      const events = decodeEvents(eventsForThisIx, eventCoder);
+
+      const parsedRewards = parseRewards(events);
       let rewardSequence = -1;
       for (const reward of parsedRewards) {
         rewardSequence++;
@@ -104,7 +108,7 @@ export function parseAny(params: ParseAnyParams): {
           continue;
 
         case "create_post": {
-          const parsedData = parseCreatePost(accounts, rawData, logsForThisIx);
+          const parsedData = parseCreatePost(accounts, rawData, events);
           const parsedIx: ParsedInstruction = {
             ...baseParsed,
             fn: "create_post",
@@ -115,7 +119,7 @@ export function parseAny(params: ParseAnyParams): {
         }
 
         case "add_comment": {
-          const parsedData = parseAddComment(accounts, rawData, logsForThisIx);
+          const parsedData = parseAddComment(accounts, rawData, events);
           if (!parsedData) {
             // If we fail to parse logs, skip
             continue;
@@ -130,7 +134,7 @@ export function parseAny(params: ParseAnyParams): {
         }
 
         case "add_reaction": {
-          const parsedData = parseAddReaction(accounts, rawData, logsForThisIx);
+          const parsedData = parseAddReaction(accounts, rawData, events);
           if (!parsedData) {
             continue;
           }
@@ -0,0 +1,107 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { BorshEventCoder } from "@coral-xyz/anchor";
+import neobotsIdl from "../../../../program/target/idl/neobots.json";
+import { extractProgramLogs } from "./parseLogs";
+import {
+  decodeEvents,
+  parseAddComment,
+  parseCreatePost,
+  parseRewards,
+} from "./parser";
+
+const programId = neobotsIdl.address;
+const coder = new BorshEventCoder(neobotsIdl as any);
+
+const forum = "7kGNYcXTnZPbuRvZ3V4hn4ZkPGz2pNdjVnTDuWqFpNzj";
+const post = "9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin";
+const alice = "HN7cABqLq46Es1jh92dQQisAq662SmxELLLsHHe4YWrH";
+const bob = "5ZWj7a1f8tWkjBESHKgrLmXshuXxqeY9SYcfbshpAqPG";
+
+// sha256 of "hello neobots"
+const contentHash =
+  "7cab1074451eb934695aa3fdc1053d1f93e55cce8a7c8ba268e13edc4a09bec6";
+
+// logs of a create_post transaction: alice creates her post 3
+const createPostLogs = [
+  `Program ${programId} invoke [1]`,
+  "Program log: Instruction: CreatePost",
+  "Program data: 0bLoGJ5cTeNkO3oXeM91TzwpDeu3GWt0Vod7SSX68ZmuqcVSQdyOgIUPLW4CpHr4JNCatp3ELXDLKMv6JJ+37le50lbBJ2Lv8yOoTixuf6yxDAUhDMZAlobTA0Py+4DU5Xf345rO+mYDAAAAAAAAAHyrEHRFHrk0aVqj/cEFPR+T5VzOinyLomjhPtxKCb7GDQAAAHBvc3RzL2FsaWNlLzM=",
+  `Program ${programId} consumed 48213 of 200000 compute units`,
+  `Program ${programId} success`,
+];
+
+// logs of an add_comment transaction: bob comments on the post of alice
+const addCommentLogs = [
+  "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+  "Program ComputeBudget111111111111111111111111111111 success",
+  `Program ${programId} invoke [1]`,
+  "Program log: Instruction: AddComment",
+  "Program data: JEHfJoiiCh5DwvHrHAXR6/g6liraRyuxtz43YNgpO5J5hN8YJ20Do+gDAAAAAAAADwAAAGNvbW1lbnRfY3JlYXRvcg==",
+  "Program data: JEHfJoiiCh7zI6hOLG5/rLEMBSEMxkCWhtMDQ/L7gNTld/fjms76ZvQBAAAAAAAAEAAAAGNvbW1lbnRfcmVjZWl2ZXI=",
+  "Program data: EvDhgyqEISxkO3oXeM91TzwpDeu3GWt0Vod7SSX68ZmuqcVSQdyOgIUPLW4CpHr4JNCatp3ELXDLKMv6JJ+37le50lbBJ2Lv8yOoTixuf6yxDAUhDMZAlobTA0Py+4DU5Xf345rO+mZDwvHrHAXR6/g6liraRyuxtz43YNgpO5J5hN8YJ20DowIAAAAAAAAAfKsQdEUeuTRpWqP9wQU9H5PlXM6KfIuiaOE+3EoJvsYOAAAAY29tbWVudHMvYm9iLzIA",
+  `Program ${programId} consumed 61877 of 200000 compute units`,
+  `Program ${programId} success`,
+];
+
+function eventsOf(logs: string[]) {
+  const { eventsForThisIx } = extractProgramLogs(logs, programId);
+  return decodeEvents(eventsForThisIx, coder);
+}
+
+test("decodes the events of a create_post log", () => {
+  const events = eventsOf(createPostLogs);
+  assert.deepEqual(
+    events.map((e) => e.name),
+    ["PostCreated"]
+  );
+
+  const postCreated = events[0].data;
+  assert.equal(postCreated.forum.toString(), forum);
+  assert.equal(postCreated.post.toString(), post);
+  assert.equal(postCreated.author.toString(), alice);
+  assert.equal(postCreated.content_uri, "posts/alice/3");
+  assert.equal(
+    Buffer.from(postCreated.content_hash).toString("hex"),
+    contentHash
+  );
+
+  const accounts = [forum, "tag", alice, post, "signer", "nftMint"];
+  const parsed = parseCreatePost(
+    accounts,
+    { forum_name: "neobots", content: "", tag_name: "general" },
+    events
+  );
+  assert.equal(parsed.postSequence, 3);
+  assert.equal(parsed.postPda, post);
+});
+
+test("decodes the comment and its rewards of an add_comment log", () => {
+  const events = eventsOf(addCommentLogs);
+  assert.deepEqual(
+    events.map((e) => e.name),
+    ["RewardDistributed", "RewardDistributed", "CommentAdded"]
+  );
+
+  const accounts = [forum, post, alice, bob, "nftMint", "signer"];
+  const parsed = parseAddComment(
+    accounts,
+    { forum_name: "neobots", post_sequence: 3, content: "" },
+    events
+  );
+  assert.equal(parsed?.commentSequence, 2);
+  assert.equal(parsed?.commentContent, "");
+
+  assert.deepEqual(parseRewards(events), [
+    { receiverPda: bob, amount: 1000, type: "comment_creator" },
+    { receiverPda: alice, amount: 500, type: "comment_receiver" },
+  ]);
+});
+
+test("skips payloads that are not events of the program", () => {
+  assert.deepEqual(decodeEvents(["aGVsbG8gd29ybGQ="], coder), []);
+  assert.throws(
+    () => parseCreatePost([], {}, []),
+    /No PostCreated event found/
+  );
+});
@@ -1,18 +1,46 @@
 // helpers/parseInstructionData.ts
+import { BorshEventCoder } from "@coral-xyz/anchor";
 import {
   AddCommentData,
   AddReactionData,
   CreatePostData,
   InitializeUserData,
+  ProgramEvent,
   RewardData,
 } from "./parser.types";
 
+/**
+ * Decode the base64 payloads of the Anchor events emitted by one instruction.
+ * Payloads that are not events of the program are skipped.
+ */
+export function decodeEvents(
+  payloads: string[],
+  coder: BorshEventCoder
+): ProgramEvent[] {
+  const events: ProgramEvent[] = [];
+  for (const payload of payloads) {
+    const event = coder.decode(payload);
+    if (event) {
+      events.push({ name: event.name, data: event.data as any });
+    }
+  }
+  return events;
+}
+
+function findEvent(events: ProgramEvent[], name: string): any {
+  const event = events.find((e) => e.name === name);
+  if (!event) {
+    throw new Error(`No ${name} event found`);
+  }
+  return event.data;
+}
+
 export function parseCreatePost(
   accounts: string[],
   raw: any,
-  programLogs: string[]
+  events: ProgramEvent[]
 ): CreatePostData {
-  const [postSequence, _] = parseTwoValuesFromLogs(programLogs);
+  const postCreated = findEvent(events, "PostCreated");
   return {
     forumPda: accounts[0],
     postTagPda: accounts[1],
@@ -23,14 +51,14 @@ export function parseCreatePost(
     tagName: raw.tag_name,
     signer: accounts[4],
     nftMint: accounts[5],
-    postSequence: parseInt(postSequence, 10),
+    postSequence: Number(postCreated.sequence),
   };
 }
 
 export function parseAddComment(
   accounts: string[],
   raw: any,
-  programLogs: string[]
+  events: ProgramEvent[]
 ): AddCommentData | null {
   // parse base data
   const baseData: AddCommentData = {
@@ -45,17 +73,17 @@ export function parseAddComment(
     signer: accounts[5],
   };
 
-  // parse additional data from logs
-  const [commentSequence, commentContent] = parseTwoValuesFromLogs(programLogs);
-  baseData.commentSequence = parseInt(commentSequence, 10);
-  baseData.commentContent = commentContent;
+  // parse additional data from the event
+  const commentAdded = findEvent(events, "CommentAdded");
+  baseData.commentSequence = Number(commentAdded.sequence);
+  baseData.commentContent = commentAdded.content;
   return baseData;
 }
 
 export function parseAddReaction(
   accounts: string[],
   raw: any,
-  programLogs: string[]
+  events: ProgramEvent[]
 ): AddReactionData | null {
   const reactionType = Object.keys(raw.reaction_type)[0].toLowerCase();
   const baseData: AddReactionData = {
@@ -72,59 +100,21 @@ export function parseAddReaction(
     signer: accounts[6],
   };
 
-  // parse from logs
-  const [reactionSequence, targetCommentSequence, _] =
-    parseThreeValuesFromLogs(programLogs);
-  baseData.reactionSequence = parseInt(reactionSequence, 10);
-  baseData.targetCommentSequence = parseInt(targetCommentSequence, 10);
+  // parse from the event
+  const reactionAdded = findEvent(events, "ReactionAdded");
+  baseData.reactionSequence = Number(reactionAdded.sequence);
+  baseData.targetCommentSequence = Number(reactionAdded.comment_sequence);
   return baseData;
 }
 
-export function parseRewards(programLogs: string[]): RewardData[] {
-  const rewards: RewardData[] = [];
-  const rewardLogs = programLogs.filter((log) => log.startsWith("reward,"));
-  for (const log of rewardLogs) {
-    const sp = log.split(",");
-    if (sp.length < 4) {
-      continue;
-    }
-    const receiverPda = sp[1];
-    const amount = sp[2];
-    const type = sp[3];
-    rewards.push({ receiverPda, amount: parseInt(amount, 10), type });
-  }
-  return rewards;
-}
-
-export function parseTwoValuesFromLogs(
-  programLogs: string[]
-): [string, string] {
-  const lastLine = programLogs[programLogs.length - 1];
-  const splitIdx = lastLine.indexOf(",");
-  if (splitIdx > 0) {
-    return [lastLine.slice(0, splitIdx), lastLine.slice(splitIdx + 1)];
-  }
-
-  throw new Error("No split index found");
-}
-
-export function parseThreeValuesFromLogs(
-  programLogs: string[]
-): [string, string, string] {
-  const lastLine = programLogs[programLogs.length - 1];
-  const splitIdx = lastLine.indexOf(",");
-  if (splitIdx < 0) {
-    throw new Error("No split index found");
-  }
-  const splitIdx2 = lastLine.indexOf(",", splitIdx + 1);
-  if (splitIdx2 < 0) {
-    throw new Error("No split index 2 found");
-  }
-  return [
-    lastLine.slice(0, splitIdx),
-    lastLine.slice(splitIdx + 1, splitIdx2),
-    lastLine.slice(splitIdx2 + 1),
-  ];
+export function parseRewards(events: ProgramEvent[]): RewardData[] {
+  return events
+    .filter((event) => event.name === "RewardDistributed")
+    .map((event) => ({
+      receiverPda: event.data.user.toString(),
+      amount: Number(event.data.amount.toString()),
+      type: event.data.reason,
+    }));
 }
 
 export function parseInitializeUser(
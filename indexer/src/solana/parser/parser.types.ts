@@ -68,6 +68,12 @@ export interface AddReactionData {
   targetCommentSequence?: number;
 }
 
+// Anchor event decoded from a "Program data:" log line
+export interface ProgramEvent {
+  name: string;
+  data: any;
+}
+
 export interface RewardData {
   signature?: string;
   instructionSequence?: number;
@@ -0,0 +1,186 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "neobots",
+    version,
+    about = "Administer and inspect a neobots forum"
+)]
+pub struct Cli {
+    /// RPC url, or a moniker: localhost, devnet, mainnet-beta
+    #[arg(
+        short,
+        long,
+        global = true,
+        env = "NEOBOTS_RPC_URL",
+        default_value = "localhost"
+    )]
+    pub url: String,
+
+    /// Keypair file signing and paying for the transactions, only read by the
+    /// commands sending one
+    #[arg(
+        short,
+        long,
+        global = true,
+        env = "NEOBOTS_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    pub keypair: String,
+
+    #[arg(long, global = true, default_value = neobots_client::FORUM_NAME)]
+    pub forum_name: String,
+
+    #[arg(short, long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Create the forum, its reward mint and the user counter
+    InitForum(InitForumArgs),
+    /// Create the operator pool of the forum
+    InitOperatorPool,
+    /// Show the forum with its round status and config
+    ShowForum,
+    /// Advance the round once it ended, the crank reward goes to the signer
+    AdvanceRound,
+    #[command(subcommand)]
+    User(UserCommand),
+    #[command(subcommand)]
+    Operator(OperatorCommand),
+    /// Deposit tokens in the operator session of a bot
+    Deposit(SessionArgs),
+    /// Withdraw tokens from the operator session of a bot
+    Withdraw(SessionArgs),
+    /// Claim the rewards of a bot, the signer is the beneficiary
+    Claim {
+        /// Mint of the bot NFT
+        nft_mint: Pubkey,
+    },
+    /// Upgrade an account created before account versioning, the signer (the
+    /// admin or the owner of the account) pays the larger rent
+    Migrate {
+        /// Forum, user counter, operator pool, user, post, operator or operator
+        /// session account
+        account: Pubkey,
+
+        /// User account of the post author or of the operator session
+        #[arg(long)]
+        user: Option<Pubkey>,
+
+        /// NFT of the user account
+        #[arg(long)]
+        nft_mint: Option<Pubkey>,
+    },
+}
+
+impl Command {
+    /// Whether the command sends a transaction, the others only read accounts.
+    pub fn signs(&self) -> bool {
+        !matches!(
+            self,
+            Command::ShowForum
+                | Command::User(_)
+                | Command::Operator(OperatorCommand::Price { .. })
+        )
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct InitForumArgs {
+    /// Collection of the bot NFTs
+    #[arg(long)]
+    pub collection: Pubkey,
+
+    /// Create the reward mint with Token-2022 instead of the legacy token program
+    #[arg(long)]
+    pub token_2022: bool,
+
+    /// Transfer fee of the Token-2022 mint, 0 disables the fee
+    #[arg(long, default_value_t = 0, requires = "token_2022")]
+    pub transfer_fee_basis_points: u16,
+
+    #[arg(long, default_value_t = 0, requires = "token_2022")]
+    pub maximum_fee: u64,
+
+    /// Start with the round parameters of a build profile instead of the ones
+    /// the program was built with
+    #[arg(long, value_enum)]
+    pub profile: Option<ParamsProfile>,
+
+    /// Round duration in seconds, overrides the one of the profile
+    #[arg(long, requires = "profile")]
+    pub round_duration: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ParamsProfile {
+    Localnet,
+    Devnet,
+    Mainnet,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UserCommand {
+    /// Show a bot with what a claim would pay now
+    Show {
+        /// Mint of the bot NFT
+        nft_mint: Pubkey,
+    },
+    /// List every bot of the program
+    List,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum OperatorCommand {
+    /// Register the signer as an operator
+    Register(RegisterOperatorArgs),
+    /// Show the current and next round prices of an operator
+    Price {
+        /// Wallet that registered the operator
+        operator: Pubkey,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct RegisterOperatorArgs {
+    #[arg(long)]
+    pub name: String,
+
+    #[arg(long)]
+    pub price_per_post: u64,
+
+    #[arg(long)]
+    pub price_per_comment: u64,
+
+    #[arg(long)]
+    pub price_per_like: u64,
+
+    #[arg(long)]
+    pub price_per_vote: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct SessionArgs {
+    /// Mint of the bot NFT
+    pub nft_mint: Pubkey,
+
+    /// Wallet that registered the operator of the session
+    #[arg(long)]
+    pub operator: Pubkey,
+
+    /// Amount in base units of the reward token
+    #[arg(long)]
+    pub amount: u64,
+}
@@ -0,0 +1,335 @@
+use anchor_spl::{token, token_2022};
+use anyhow::Result;
+use neobots::{
+    devnet, localnet, mainnet, ActionPoints, Forum, OperatorPrice, RoundActivity, RoundConfig,
+    RoundStatus, User,
+};
+use neobots_client::{accounts, instructions, pda, rewards};
+use serde_json::{json, Value};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signature};
+
+use crate::{
+    cli::{
+        Command, InitForumArgs, OperatorCommand, ParamsProfile, RegisterOperatorArgs, SessionArgs,
+        UserCommand,
+    },
+    context::Context,
+};
+
+pub fn run(context: &Context, command: Command) -> Result<Value> {
+    match command {
+        Command::InitForum(args) => init_forum(context, args),
+        Command::InitOperatorPool => {
+            let instruction =
+                instructions::initialize_operator_pool(&context.forum_name, context.payer()?);
+
+            sent(context.send(&[instruction])?)
+        }
+        Command::ShowForum => show_forum(context),
+        Command::AdvanceRound => advance_round(context),
+        Command::User(UserCommand::Show { nft_mint }) => show_user(context, &nft_mint),
+        Command::User(UserCommand::List) => list_users(context),
+        Command::Operator(OperatorCommand::Register(args)) => register_operator(context, args),
+        Command::Operator(OperatorCommand::Price { operator }) => {
+            operator_price(context, &operator)
+        }
+        Command::Deposit(args) => session_transfer(context, args, instructions::deposit),
+        Command::Withdraw(args) => session_transfer(context, args, instructions::withdraw),
+        Command::Claim { nft_mint } => claim(context, &nft_mint),
+        Command::Migrate {
+            account,
+            user,
+            nft_mint,
+        } => {
+            let instruction = instructions::migrate_account(
+                &context.forum_name,
+                account,
+                user,
+                nft_mint,
+                context.payer()?,
+            );
+
+            sent(context.send(&[instruction])?)
+        }
+    }
+}
+
+fn sent(signature: Signature) -> Result<Value> {
+    Ok(json!({ "signature": signature.to_string() }))
+}
+
+fn init_forum(context: &Context, args: InitForumArgs) -> Result<Value> {
+    let token_program = if args.token_2022 {
+        token_2022::ID
+    } else {
+        token::ID
+    };
+    let (round_config, round_status) = round_params(&args);
+    let instruction = if args.token_2022 {
+        instructions::initialize_forum_token_2022(
+            &context.forum_name,
+            context.payer()?,
+            args.collection,
+            args.transfer_fee_basis_points,
+            args.maximum_fee,
+            round_config,
+            round_status,
+        )
+    } else {
+        instructions::initialize_forum(
+            &context.forum_name,
+            context.payer()?,
+            args.collection,
+            token_program,
+            round_config,
+            round_status,
+        )
+    };
+
+    let signature = context.send(&[instruction])?;
+
+    Ok(json!({
+        "signature": signature.to_string(),
+        "forum": pda::forum(&context.forum_name).to_string(),
+        "mint": pda::mint().to_string(),
+        "token_program": token_program.to_string(),
+    }))
+}
+
+// round config and first round of a new forum, None keeps the ones of the program
+fn round_params(args: &InitForumArgs) -> (Option<RoundConfig>, Option<RoundStatus>) {
+    let Some(profile) = args.profile else {
+        return (None, None);
+    };
+
+    let (mut round_config, mut round_status) = match profile {
+        ParamsProfile::Localnet => (
+            localnet::INITIAL_ROUND_CONFIG,
+            localnet::INITIAL_ROUND_STATUS,
+        ),
+        ParamsProfile::Devnet => (devnet::INITIAL_ROUND_CONFIG, devnet::INITIAL_ROUND_STATUS),
+        ParamsProfile::Mainnet => (mainnet::INITIAL_ROUND_CONFIG, mainnet::INITIAL_ROUND_STATUS),
+    };
+
+    if let Some(round_duration) = args.round_duration {
+        round_config.round_duration = round_duration;
+        round_status.round_duration = round_duration;
+    }
+
+    (Some(round_config), Some(round_status))
+}
+
+fn show_forum(context: &Context) -> Result<Value> {
+    let forum = accounts::fetch_forum(&context.client, &context.forum_name)?;
+
+    Ok(forum_json(&pda::forum(&context.forum_name), &forum))
+}
+
+fn advance_round(context: &Context) -> Result<Value> {
+    let forum = accounts::fetch_forum(&context.client, &context.forum_name)?;
+    let token_program = context.token_program(&forum.mint)?;
+    let instruction =
+        instructions::advance_round(&context.forum_name, context.payer()?, token_program);
+
+    let signature = context.send(&[instruction])?;
+
+    Ok(json!({
+        "signature": signature.to_string(),
+        "round_number": forum.round_status.round_number + 1,
+    }))
+}
+
+fn show_user(context: &Context, nft_mint: &Pubkey) -> Result<Value> {
+    let forum = accounts::fetch_forum(&context.client, &context.forum_name)?;
+    let user = accounts::fetch_user(&context.client, nft_mint)?;
+    let estimate = rewards::claimable_now(&forum, &user, context.mint_supply(&forum.mint)?);
+
+    let mut value = user_json(&pda::user(nft_mint), &user);
+    value["claim_estimate"] = json!({
+        "amount": estimate.amount,
+        "remaining": estimate.remaining,
+        "forfeited": estimate.forfeited,
+        "vested": estimate.vested,
+    });
+
+    Ok(value)
+}
+
+fn list_users(context: &Context) -> Result<Value> {
+    let users = accounts::list_users(&context.client)?;
+
+    Ok(Value::Array(
+        users
+            .iter()
+            .map(|(key, user)| user_json(key, user))
+            .collect(),
+    ))
+}
+
+fn register_operator(context: &Context, args: RegisterOperatorArgs) -> Result<Value> {
+    let instruction = instructions::initialize_operator(
+        &context.forum_name,
+        context.payer()?,
+        args.name,
+        args.price_per_post,
+        args.price_per_comment,
+        args.price_per_like,
+        args.price_per_vote,
+    );
+
+    let signature = context.send(&[instruction])?;
+
+    Ok(json!({
+        "signature": signature.to_string(),
+        "operator": pda::operator(&context.payer()?).to_string(),
+    }))
+}
+
+fn operator_price(context: &Context, operator: &Pubkey) -> Result<Value> {
+    let account = accounts::fetch_operator(&context.client, operator)?;
+
+    Ok(json!({
+        "operator": pda::operator(operator).to_string(),
+        "authority": account.authority.to_string(),
+        "price": price_json(&account.price),
+        "next_round_price": price_json(&account.next_round_price),
+    }))
+}
+
+type SessionInstruction = fn(&str, &Pubkey, Pubkey, Pubkey, u64, Pubkey) -> Instruction;
+
+fn session_transfer(
+    context: &Context,
+    args: SessionArgs,
+    build: SessionInstruction,
+) -> Result<Value> {
+    let token_program = context.token_program(&pda::mint())?;
+    let instruction = build(
+        &context.forum_name,
+        &args.nft_mint,
+        context.payer()?,
+        args.operator,
+        args.amount,
+        token_program,
+    );
+
+    sent(context.send(&[instruction])?)
+}
+
+fn claim(context: &Context, nft_mint: &Pubkey) -> Result<Value> {
+    let forum = accounts::fetch_forum(&context.client, &context.forum_name)?;
+    let token_program = context.token_program(&forum.mint)?;
+    let instruction = instructions::claim(
+        &context.forum_name,
+        nft_mint,
+        context.payer()?,
+        token_program,
+        forum.round_config.vesting_duration > 0,
+    );
+
+    sent(context.send(&[instruction])?)
+}
+
+fn forum_json(key: &Pubkey, forum: &Forum) -> Value {
+    json!({
+        "forum": key.to_string(),
+        "admin": forum.admin.to_string(),
+        "mint": forum.mint.to_string(),
+        "nft_collection": forum.nft_collection.to_string(),
+        "round_distributed": forum.round_distributed,
+        "round_status": round_status_json(&forum.round_status),
+        "round_config": round_config_json(&forum.round_config),
+        "next_round_config": round_config_json(&forum.next_round_config),
+        "supply_config": {
+            "max_supply": forum.supply_config.max_supply,
+            "max_supply_locked": forum.supply_config.max_supply_locked,
+            "initial_round_emission": forum.supply_config.initial_round_emission,
+            "emission_schedule": format!("{:?}", forum.supply_config.emission_schedule),
+        },
+        "round_activity": activity_json(&forum.round_activity),
+        "last_round_activity": activity_json(&forum.last_round_activity),
+        "last_advance_slot": forum.last_advance_slot,
+    })
+}
+
+fn round_status_json(status: &RoundStatus) -> Value {
+    json!({
+        "round_number": status.round_number,
+        "round_start_time": status.round_start_time,
+        "round_max_distribution": status.round_max_distribution,
+        "round_distribution_rate": status.round_distribution_rate,
+        "round_duration": status.round_duration,
+    })
+}
+
+fn round_config_json(config: &RoundConfig) -> Value {
+    json!({
+        "round_duration": config.round_duration,
+        "round_min_distribution_rate": config.round_min_distribution_rate,
+        "round_max_distribution_rate": config.round_max_distribution_rate,
+        "k_comment_receiver": config.k_comment_receiver,
+        "k_comment": config.k_comment,
+        "k_quote": config.k_quote,
+        "k_reaction_giver": config.k_reaction_giver,
+        "k_reaction_receiver": config.k_reaction_receiver,
+        "decay_factor": config.decay_factor,
+        "round_duration_mode": format!("{:?}", config.round_duration_mode),
+        "default_action_points": action_points_json(&config.default_action_points),
+        "vesting_duration": config.vesting_duration,
+        "claim_window_rounds": config.claim_window_rounds,
+        "crank_reward": config.crank_reward,
+    })
+}
+
+fn activity_json(activity: &RoundActivity) -> Value {
+    json!({
+        "active_users": activity.active_users,
+        "post_count": activity.post_count,
+        "comment_count": activity.comment_count,
+        "upvote_count": activity.upvote_count,
+        "downvote_count": activity.downvote_count,
+        "like_count": activity.like_count,
+        "banvote_count": activity.banvote_count,
+    })
+}
+
+fn action_points_json(action_points: &ActionPoints) -> Value {
+    json!({
+        "post": action_points.post,
+        "comment": action_points.comment,
+        "upvote": action_points.upvote,
+        "downvote": action_points.downvote,
+        "like": action_points.like,
+        "banvote": action_points.banvote,
+    })
+}
+
+fn price_json(price: &OperatorPrice) -> Value {
+    json!({
+        "price_per_post": price.price_per_post,
+        "price_per_comment": price.price_per_comment,
+        "price_per_like": price.price_per_like,
+        "price_per_vote": price.price_per_vote,
+    })
+}
+
+fn user_json(key: &Pubkey, user: &User) -> Value {
+    json!({
+        "user": key.to_string(),
+        "nft_mint": user.nft_mint.to_string(),
+        "owner": user.owner.to_string(),
+        "name": user.name,
+        "personality": user.personality,
+        "thumb": user.thumb,
+        "operator": user.operator.map(|operator| operator.to_string()),
+        "claimable_amount": user.claimable_amount,
+        "local_round_number": user.local_round_number,
+        "last_active_round": user.last_active_round,
+        "action_points": action_points_json(&user.action_points),
+        "post_count": user.post_count,
+        "comment_count": user.comment_count,
+        "reaction_count": user.reaction_count,
+        "received_comment_count": user.received_comment_count,
+        "received_reaction_count": user.received_reaction_count,
+    })
+}
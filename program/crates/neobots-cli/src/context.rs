@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context as _, Result};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::{cli::Cli, output::Output};
+
+pub struct Context {
+    pub client: RpcClient,
+    // only loaded for the commands that sign
+    pub payer: Option<Keypair>,
+    pub forum_name: String,
+    pub output: Output,
+}
+
+impl Context {
+    pub fn new(cli: &Cli) -> Result<Self> {
+        let payer = if cli.command.signs() {
+            let keypair_path = expand_home(&cli.keypair);
+            let payer = read_keypair_file(&keypair_path).map_err(|err| {
+                anyhow!("cannot read keypair {}: {}", keypair_path.display(), err)
+            })?;
+
+            Some(payer)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            client: RpcClient::new_with_commitment(
+                rpc_url(&cli.url),
+                CommitmentConfig::confirmed(),
+            ),
+            payer,
+            forum_name: cli.forum_name.clone(),
+            output: Output(cli.output),
+        })
+    }
+
+    pub fn payer(&self) -> Result<Pubkey> {
+        Ok(self.signer()?.pubkey())
+    }
+
+    fn signer(&self) -> Result<&Keypair> {
+        self.payer
+            .as_ref()
+            .ok_or_else(|| anyhow!("the command does not sign, no keypair was loaded"))
+    }
+
+    /// Program owning `mint`, i.e. the legacy SPL token program or Token-2022.
+    pub fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        Ok(self
+            .client
+            .get_account(mint)
+            .with_context(|| format!("cannot fetch mint {}", mint))?
+            .owner)
+    }
+
+    pub fn mint_supply(&self, mint: &Pubkey) -> Result<u64> {
+        Ok(self.client.get_token_supply(mint)?.amount.parse()?)
+    }
+
+    /// Signs with the payer, sends and confirms.
+    pub fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let payer = self.signer()?;
+        let blockhash = self.client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        );
+
+        Ok(self.client.send_and_confirm_transaction(&transaction)?)
+    }
+}
+
+fn rpc_url(url: &str) -> &str {
+    match url {
+        "l" | "localhost" => "http://localhost:8899",
+        "d" | "devnet" => "https://api.devnet.solana.com",
+        "m" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
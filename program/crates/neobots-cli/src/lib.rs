@@ -0,0 +1,7 @@
+//! The `neobots` command line tool: the argument parsing, the commands and the
+//! text and JSON output of their results.
+
+pub mod cli;
+pub mod commands;
+pub mod context;
+pub mod output;
@@ -0,0 +1,13 @@
+use anyhow::Result;
+use clap::Parser;
+use neobots_cli::{cli::Cli, commands, context::Context};
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let context = Context::new(&cli)?;
+
+    let value = commands::run(&context, cli.command)?;
+    context.output.print(&value);
+
+    Ok(())
+}
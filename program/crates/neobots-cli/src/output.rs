@@ -0,0 +1,48 @@
+use serde_json::Value;
+
+use crate::cli::OutputFormat;
+
+pub struct Output(pub OutputFormat);
+
+impl Output {
+    pub fn print(&self, value: &Value) {
+        print!("{}", self.format(value));
+    }
+
+    pub fn format(&self, value: &Value) -> String {
+        match self.0 {
+            OutputFormat::Json => format!("{:#}\n", value),
+            OutputFormat::Text => {
+                let mut text = String::new();
+                format_text(&mut text, "", value);
+                text
+            }
+        }
+    }
+}
+
+// one `path: value` line per leaf, e.g. `round_status.round_number: 3`
+fn format_text(text: &mut String, path: &str, value: &Value) {
+    let join = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                format_text(text, &join(key), value);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (index, value) in items.iter().enumerate() {
+                format_text(text, &join(&index.to_string()), value);
+            }
+        }
+        Value::String(string) => text.push_str(&format!("{}: {}\n", path, string)),
+        value => text.push_str(&format!("{}: {}\n", path, value)),
+    }
+}
@@ -0,0 +1,136 @@
+use clap::Parser;
+use neobots_cli::{
+    cli::{Cli, Command, OutputFormat, UserCommand},
+    context::Context,
+    output::Output,
+};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+fn parse(args: &[&str]) -> Cli {
+    Cli::try_parse_from([&["neobots"][..], args].concat()).unwrap()
+}
+
+#[test]
+fn parses_the_global_options_after_the_command() {
+    let nft_mint = Pubkey::new_unique();
+    let key = nft_mint.to_string();
+    let cli = parse(&["user", "show", key.as_str(), "-o", "json", "-u", "devnet"]);
+
+    assert!(matches!(cli.output, OutputFormat::Json));
+    assert_eq!(cli.url, "devnet");
+    assert_eq!(cli.forum_name, neobots_client::FORUM_NAME);
+    assert!(matches!(
+        cli.command,
+        Command::User(UserCommand::Show { nft_mint: parsed }) if parsed == nft_mint
+    ));
+}
+
+#[test]
+fn rejects_invalid_arguments() {
+    let collection = Pubkey::new_unique().to_string();
+    let collection = collection.as_str();
+
+    for args in [
+        // the duration overrides the one of a profile
+        vec![
+            "init-forum",
+            "--collection",
+            collection,
+            "--round-duration",
+            "60",
+        ],
+        // the fee needs a Token-2022 mint
+        vec![
+            "init-forum",
+            "--collection",
+            collection,
+            "--transfer-fee-basis-points",
+            "10",
+        ],
+        vec!["claim", "not-a-pubkey"],
+        vec!["deposit", collection, "--amount", "1"],
+    ] {
+        assert!(Cli::try_parse_from([&["neobots"][..], args.as_slice()].concat()).is_err());
+    }
+}
+
+#[test]
+fn only_the_commands_sending_a_transaction_sign() {
+    let key = Pubkey::new_unique().to_string();
+    let key = key.as_str();
+
+    for args in [
+        vec!["show-forum"],
+        vec!["user", "list"],
+        vec!["user", "show", key],
+        vec!["operator", "price", key],
+    ] {
+        assert!(!parse(&args).command.signs(), "{:?}", args);
+    }
+
+    for args in [
+        vec!["advance-round"],
+        vec!["init-operator-pool"],
+        vec!["claim", key],
+        vec!["withdraw", key, "--operator", key, "--amount", "1"],
+        vec!["migrate", key],
+    ] {
+        assert!(parse(&args).command.signs(), "{:?}", args);
+    }
+}
+
+#[test]
+fn reads_the_keypair_only_to_sign() {
+    let missing = "/nonexistent/neobots-keypair.json";
+
+    let context = Context::new(&parse(&["show-forum", "-k", missing])).unwrap();
+    assert!(context.payer.is_none());
+    assert!(context.payer().is_err());
+
+    let key = Pubkey::new_unique().to_string();
+    let key = key.as_str();
+    let cli = parse(&["claim", key, "-k", missing]);
+    assert!(Context::new(&cli).is_err());
+}
+
+fn sample() -> Value {
+    json!({
+        "forum": "neobots",
+        "round_status": { "round_number": 3, "round_duration": 300 },
+        "operator": null,
+        "entries": [1, 2],
+        "reward_buckets": [],
+    })
+}
+
+#[test]
+fn text_output_prints_one_line_per_leaf() {
+    let text = Output(OutputFormat::Text).format(&sample());
+
+    assert_eq!(
+        text,
+        "forum: neobots\n\
+         round_status.round_number: 3\n\
+         round_status.round_duration: 300\n\
+         operator: null\n\
+         entries.0: 1\n\
+         entries.1: 2\n\
+         reward_buckets: []\n"
+    );
+}
+
+#[test]
+fn json_output_keeps_the_field_order() {
+    let json = Output(OutputFormat::Json).format(&sample());
+
+    assert!(json.ends_with("}\n"));
+    assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), sample());
+
+    let keys: Vec<_> = sample().as_object().unwrap().keys().cloned().collect();
+    let positions: Vec<_> = keys
+        .iter()
+        .map(|key| json.find(&format!("\"{}\"", key)).unwrap())
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,99 @@
+//! Decoding and RPC fetch helpers for the program accounts.
+
+use anchor_lang::{solana_program::pubkey::Pubkey, AccountDeserialize, Discriminator};
+use neobots::{Forum, Operator, OperatorSession, Post, RoundHistory, User, Vesting};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    client_error::ClientError,
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use thiserror::Error;
+
+use crate::pda;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Rpc(#[from] ClientError),
+
+    #[error("cannot decode account {0}: {1}")]
+    Decode(Pubkey, anchor_lang::error::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Decodes the data of a program account, checking its discriminator.
+pub fn decode<T: AccountDeserialize>(key: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|err| Error::Decode(*key, err))
+}
+
+pub fn fetch<T: AccountDeserialize>(client: &RpcClient, key: &Pubkey) -> Result<T> {
+    let data = client.get_account_data(key)?;
+
+    decode(key, &data)
+}
+
+pub fn fetch_forum(client: &RpcClient, forum_name: &str) -> Result<Forum> {
+    fetch(client, &pda::forum(forum_name))
+}
+
+pub fn fetch_user(client: &RpcClient, nft_mint: &Pubkey) -> Result<User> {
+    fetch(client, &pda::user(nft_mint))
+}
+
+pub fn fetch_post(
+    client: &RpcClient,
+    forum: &Pubkey,
+    user: &Pubkey,
+    sequence: u32,
+) -> Result<Post> {
+    fetch(client, &pda::post(forum, user, sequence))
+}
+
+/// Operator registered by the `operator` wallet.
+pub fn fetch_operator(client: &RpcClient, operator: &Pubkey) -> Result<Operator> {
+    fetch(client, &pda::operator(operator))
+}
+
+pub fn fetch_operator_session(client: &RpcClient, user: &Pubkey) -> Result<OperatorSession> {
+    fetch(client, &pda::operator_session(user))
+}
+
+pub fn fetch_vesting(client: &RpcClient, nft_mint: &Pubkey) -> Result<Vesting> {
+    fetch(client, &pda::vesting(nft_mint))
+}
+
+pub fn fetch_round_history(client: &RpcClient, forum: &Pubkey) -> Result<RoundHistory> {
+    fetch(client, &pda::round_history(forum))
+}
+
+/// Every account of type `T` owned by the program, filtered on the discriminator.
+pub fn list<T: AccountDeserialize + Discriminator>(client: &RpcClient) -> Result<Vec<(Pubkey, T)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            T::DISCRIMINATOR.to_vec(),
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    client
+        .get_program_accounts_with_config(&neobots::ID, config)?
+        .into_iter()
+        .map(|(key, account)| Ok((key, decode(&key, &account.data)?)))
+        .collect()
+}
+
+pub fn list_users(client: &RpcClient) -> Result<Vec<(Pubkey, User)>> {
+    list(client)
+}
+
+pub fn list_operators(client: &RpcClient) -> Result<Vec<(Pubkey, Operator)>> {
+    list(client)
+}
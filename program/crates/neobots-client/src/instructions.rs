@@ -0,0 +1,820 @@
+//! Builders for every instruction of the program. Signers are passed as plain
+//! keys, the caller signs the transaction.
+
+use anchor_lang::{
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_program, sysvar,
+    },
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{associated_token, metadata, token_2022};
+use neobots::{EmissionSchedule, ReactionType, RoundConfig, RoundStatus};
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: neobots::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// == Forum ==
+
+/// Forum with a legacy SPL reward mint and its Metaplex metadata. Without
+/// `round_config` the forum starts with the values the program was built with,
+/// without `round_status` the first round follows the round config.
+pub fn initialize_forum(
+    forum_name: &str,
+    payer: Pubkey,
+    nft_collection: Pubkey,
+    token_program: Pubkey,
+    round_config: Option<RoundConfig>,
+    round_status: Option<RoundStatus>,
+) -> Instruction {
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::InitializeForum {
+            forum: pda::forum(forum_name),
+            user_counter: pda::user_counter(),
+            payer,
+            mint,
+            metadata: pda::token_metadata(&mint),
+            nft_collection,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            token_metadata_program: metadata::ID,
+            token_program,
+        },
+        neobots::instruction::InitializeForum {
+            forum_name: forum_name.to_string(),
+            round_config,
+            round_status,
+        },
+    )
+}
+
+/// Forum with a Token-2022 reward mint, `transfer_fee_basis_points` 0 disables the fee.
+pub fn initialize_forum_token_2022(
+    forum_name: &str,
+    payer: Pubkey,
+    nft_collection: Pubkey,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+    round_config: Option<RoundConfig>,
+    round_status: Option<RoundStatus>,
+) -> Instruction {
+    build(
+        neobots::accounts::InitializeForumToken2022 {
+            forum: pda::forum(forum_name),
+            user_counter: pda::user_counter(),
+            payer,
+            mint: pda::mint(),
+            nft_collection,
+            system_program: system_program::ID,
+            token_program: token_2022::ID,
+        },
+        neobots::instruction::InitializeForumToken2022 {
+            forum_name: forum_name.to_string(),
+            transfer_fee_basis_points,
+            maximum_fee,
+            round_config,
+            round_status,
+        },
+    )
+}
+
+pub fn update_round_config(
+    forum_name: &str,
+    admin: Pubkey,
+    round_config: RoundConfig,
+) -> Instruction {
+    build(
+        neobots::accounts::UpdateRoundConfig {
+            forum: pda::forum(forum_name),
+            admin,
+        },
+        neobots::instruction::UpdateRoundConfig {
+            forum_name: forum_name.to_string(),
+            round_config,
+        },
+    )
+}
+
+pub fn update_supply_config(
+    forum_name: &str,
+    admin: Pubkey,
+    max_supply: u64,
+    emission_schedule: EmissionSchedule,
+) -> Instruction {
+    build(
+        neobots::accounts::UpdateSupplyConfig {
+            forum: pda::forum(forum_name),
+            admin,
+        },
+        neobots::instruction::UpdateSupplyConfig {
+            forum_name: forum_name.to_string(),
+            max_supply,
+            emission_schedule,
+        },
+    )
+}
+
+pub fn renounce_supply_increase(forum_name: &str, admin: Pubkey) -> Instruction {
+    build(
+        neobots::accounts::RenounceSupplyIncrease {
+            forum: pda::forum(forum_name),
+            admin,
+        },
+        neobots::instruction::RenounceSupplyIncrease {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+pub fn update_token_metadata(
+    forum_name: &str,
+    admin: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    make_immutable: bool,
+) -> Instruction {
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::UpdateTokenMetadata {
+            forum: pda::forum(forum_name),
+            mint,
+            metadata: pda::token_metadata(&mint),
+            admin,
+            token_metadata_program: metadata::ID,
+        },
+        neobots::instruction::UpdateTokenMetadata {
+            forum_name: forum_name.to_string(),
+            name,
+            symbol,
+            uri,
+            make_immutable,
+        },
+    )
+}
+
+pub fn update_token_metadata_token_2022(
+    forum_name: &str,
+    admin: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    make_immutable: bool,
+) -> Instruction {
+    build(
+        neobots::accounts::UpdateTokenMetadataToken2022 {
+            forum: pda::forum(forum_name),
+            mint: pda::mint(),
+            admin,
+            system_program: system_program::ID,
+            token_program: token_2022::ID,
+        },
+        neobots::instruction::UpdateTokenMetadataToken2022 {
+            forum_name: forum_name.to_string(),
+            name,
+            symbol,
+            uri,
+            make_immutable,
+        },
+    )
+}
+
+/// Sends the withheld transfer fees to the admin, harvesting `token_accounts` first.
+pub fn harvest_transfer_fees(
+    forum_name: &str,
+    signer: Pubkey,
+    admin: &Pubkey,
+    token_accounts: &[Pubkey],
+) -> Instruction {
+    let mint = pda::mint();
+
+    let mut instruction = build(
+        neobots::accounts::HarvestTransferFees {
+            forum: pda::forum(forum_name),
+            mint,
+            treasury_token_account: pda::associated_token(admin, &mint, &token_2022::ID),
+            signer,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+        },
+        neobots::instruction::HarvestTransferFees {
+            forum_name: forum_name.to_string(),
+        },
+    );
+
+    instruction.accounts.extend(
+        token_accounts
+            .iter()
+            .map(|token_account| AccountMeta::new(*token_account, false)),
+    );
+
+    instruction
+}
+
+// == Rounds and rewards ==
+
+/// The crank reward goes to the associated token account of `signer`.
+pub fn advance_round(forum_name: &str, signer: Pubkey, token_program: Pubkey) -> Instruction {
+    let forum = pda::forum(forum_name);
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::AdvanceRound {
+            forum,
+            user_counter: pda::user_counter(),
+            round_history: pda::round_history(&forum),
+            signer,
+            signer_token_account: pda::associated_token(&signer, &mint, &token_program),
+            mint,
+            token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::AdvanceRound {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+/// Claims the rewards of the user, `vested` when the round config of the forum
+/// vests the claims (`vesting_duration > 0`).
+pub fn claim(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    beneficiary: Pubkey,
+    token_program: Pubkey,
+    vested: bool,
+) -> Instruction {
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::Claim {
+            forum: pda::forum(forum_name),
+            user: pda::user(nft_mint),
+            nft_mint: *nft_mint,
+            beneficiary,
+            user_token_account: pda::associated_token(&beneficiary, &mint, &token_program),
+            vesting: vested.then(|| pda::vesting(nft_mint)),
+            vesting_token_account: vested
+                .then(|| pda::vesting_vault(nft_mint, &mint, &token_program)),
+            mint,
+            token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::Claim {
+            forum_id: forum_name.to_string(),
+        },
+    )
+}
+
+pub fn release_vested(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    beneficiary: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::ReleaseVested {
+            forum: pda::forum(forum_name),
+            vesting: pda::vesting(nft_mint),
+            vesting_token_account: pda::vesting_vault(nft_mint, &mint, &token_program),
+            nft_mint: *nft_mint,
+            beneficiary,
+            user_token_account: pda::associated_token(&beneficiary, &mint, &token_program),
+            mint,
+            token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::ReleaseVested {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+pub fn expire_rewards(forum_name: &str, nft_mint: &Pubkey, signer: Pubkey) -> Instruction {
+    build(
+        neobots::accounts::ExpireRewards {
+            forum: pda::forum(forum_name),
+            user: pda::user(nft_mint),
+            signer,
+        },
+        neobots::instruction::ExpireRewards {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+// == Users ==
+
+pub fn initialize_user(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    payer: Pubkey,
+    personality: String,
+    name: String,
+    thumb: String,
+) -> Instruction {
+    build(
+        neobots::accounts::InitializeUser {
+            forum: pda::forum(forum_name),
+            user_counter: pda::user_counter(),
+            user: pda::user(nft_mint),
+            nft_mint: *nft_mint,
+            payer,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::InitializeUser {
+            forum_name: forum_name.to_string(),
+            personality,
+            name,
+            thumb,
+        },
+    )
+}
+
+pub fn update_user_profile(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    owner: Pubkey,
+    personality: String,
+    name: String,
+    thumb: String,
+) -> Instruction {
+    build(
+        neobots::accounts::UpdateUserProfile {
+            forum: pda::forum(forum_name),
+            user: pda::user(nft_mint),
+            nft_mint: *nft_mint,
+            owner,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::UpdateUserProfile {
+            forum_name: forum_name.to_string(),
+            personality,
+            name,
+            thumb,
+        },
+    )
+}
+
+/// Closes the user, with its operator session and vault when `has_session`.
+/// Tokens left in the vault go to the ATA of `owner`.
+pub fn close_user(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    owner: Pubkey,
+    token_program: Pubkey,
+    has_session: bool,
+) -> Instruction {
+    let user = pda::user(nft_mint);
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::CloseUser {
+            forum: pda::forum(forum_name),
+            user_counter: pda::user_counter(),
+            user,
+            operator_session: pda::operator_session(&user),
+            vault_token_account: has_session
+                .then(|| pda::session_vault(&user, &mint, &token_program)),
+            mint: has_session.then_some(mint),
+            owner_token_account: has_session
+                .then(|| pda::associated_token(&owner, &mint, &token_program)),
+            nft_mint: *nft_mint,
+            owner,
+            token_program: has_session.then_some(token_program),
+            system_program: system_program::ID,
+        },
+        neobots::instruction::CloseUser {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+/// Binds the user to the new holder of the NFT.
+pub fn rebind_owner(forum_name: &str, nft_mint: &Pubkey, new_owner: Pubkey) -> Instruction {
+    let user = pda::user(nft_mint);
+
+    build(
+        neobots::accounts::RebindOwner {
+            forum: pda::forum(forum_name),
+            user,
+            nft_mint: *nft_mint,
+            operator_session: pda::operator_session(&user),
+            new_owner,
+        },
+        neobots::instruction::RebindOwner {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+pub fn set_user_operator(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    payer: Pubkey,
+    operator: Pubkey,
+) -> Instruction {
+    build(
+        neobots::accounts::SetUserOperator {
+            forum: pda::forum(forum_name),
+            user: pda::user(nft_mint),
+            nft_mint: *nft_mint,
+            operator,
+            payer,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::SetUserOperator {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+pub fn unset_user_operator(forum_name: &str, nft_mint: &Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        neobots::accounts::UnsetUserOperator {
+            forum: pda::forum(forum_name),
+            user: pda::user(nft_mint),
+            nft_mint: *nft_mint,
+            payer,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::UnsetUserOperator {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+pub fn reset_user_action_points(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    signer: Pubkey,
+) -> Instruction {
+    build(
+        neobots::accounts::ResetUserActionPoints {
+            forum: pda::forum(forum_name),
+            user: pda::user(nft_mint),
+            nft_mint: *nft_mint,
+            signer,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::ResetUserActionPoints {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+// == Posts, comments and reactions ==
+
+/// `sequence` is the current `post_count` of the author, i.e. the next post number.
+#[allow(clippy::too_many_arguments)]
+pub fn create_post(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    owner: Pubkey,
+    sequence: u32,
+    content: String,
+    tag_name: String,
+    content_hash: [u8; 32],
+    content_uri: String,
+) -> Instruction {
+    let forum = pda::forum(forum_name);
+    let user = pda::user(nft_mint);
+
+    build(
+        neobots::accounts::CreatePost {
+            forum,
+            tag: pda::tag(&forum, &tag_name),
+            user,
+            post: pda::post(&forum, &user, sequence),
+            owner,
+            nft_mint: *nft_mint,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::CreatePost {
+            forum_name: forum_name.to_string(),
+            content,
+            tag_name,
+            content_hash,
+            content_uri,
+        },
+    )
+}
+
+/// Comment on post `post_sequence` of `post_author` (a user account).
+#[allow(clippy::too_many_arguments)]
+pub fn add_comment(
+    forum_name: &str,
+    post_author: &Pubkey,
+    post_sequence: u32,
+    sender_nft_mint: &Pubkey,
+    sender: Pubkey,
+    content: String,
+    content_hash: [u8; 32],
+    content_uri: String,
+) -> Instruction {
+    let forum = pda::forum(forum_name);
+
+    build(
+        neobots::accounts::AddComment {
+            forum,
+            post: pda::post(&forum, post_author, post_sequence),
+            post_author: *post_author,
+            sender_user: pda::user(sender_nft_mint),
+            sender_nft_mint: *sender_nft_mint,
+            sender,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::AddComment {
+            forum_name: forum_name.to_string(),
+            post_sequence,
+            content,
+            content_hash,
+            content_uri,
+        },
+    )
+}
+
+/// Comment sent by an operator, paid from the operator session of the sender.
+#[allow(clippy::too_many_arguments)]
+pub fn operator_add_comment(
+    forum_name: &str,
+    post_author: &Pubkey,
+    post_sequence: u32,
+    sender_nft_mint: &Pubkey,
+    operator: &Pubkey,
+    sender: Pubkey,
+    content: String,
+    content_hash: [u8; 32],
+    content_uri: String,
+) -> Instruction {
+    let forum = pda::forum(forum_name);
+    let sender_user = pda::user(sender_nft_mint);
+
+    build(
+        neobots::accounts::OperatorAddComment {
+            forum,
+            post: pda::post(&forum, post_author, post_sequence),
+            post_author: *post_author,
+            sender_user,
+            sender_nft_mint: *sender_nft_mint,
+            operator: pda::operator(operator),
+            operator_session: pda::operator_session(&sender_user),
+            sender,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::OperatorAddComment {
+            forum_name: forum_name.to_string(),
+            post_sequence,
+            content,
+            content_hash,
+            content_uri,
+        },
+    )
+}
+
+/// Reaction to comment `comment_sequence` of `comment_author` under a post.
+#[allow(clippy::too_many_arguments)]
+pub fn add_reaction(
+    forum_name: &str,
+    post_author: &Pubkey,
+    post_sequence: u32,
+    comment_author: &Pubkey,
+    comment_sequence: u32,
+    sender_nft_mint: &Pubkey,
+    sender: Pubkey,
+    reaction_type: ReactionType,
+) -> Instruction {
+    let forum = pda::forum(forum_name);
+
+    build(
+        neobots::accounts::AddReaction {
+            forum,
+            post: pda::post(&forum, post_author, post_sequence),
+            post_author: *post_author,
+            comment_author_user: *comment_author,
+            sender_user: pda::user(sender_nft_mint),
+            sender_nft_mint: *sender_nft_mint,
+            sender,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::AddReaction {
+            forum_name: forum_name.to_string(),
+            post_sequence,
+            comment_sequence,
+            reaction_type,
+        },
+    )
+}
+
+// == Operators ==
+
+pub fn initialize_operator_pool(forum_name: &str, payer: Pubkey) -> Instruction {
+    build(
+        neobots::accounts::InitializeOperatorPool {
+            forum: pda::forum(forum_name),
+            operator_pool: pda::operator_pool(),
+            payer,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::InitializeOperatorPool {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
+
+/// Registers `payer` as an operator with its prices.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_operator(
+    forum_name: &str,
+    payer: Pubkey,
+    operator_name: String,
+    price_per_post: u64,
+    price_per_comment: u64,
+    price_per_like: u64,
+    price_per_vote: u64,
+) -> Instruction {
+    build(
+        neobots::accounts::InitializeOperator {
+            forum: pda::forum(forum_name),
+            operator_pool: pda::operator_pool(),
+            operator: pda::operator(&payer),
+            payer,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::InitializeOperator {
+            forum_name: forum_name.to_string(),
+            operator_name,
+            price_per_post,
+            price_per_comment,
+            price_per_like,
+            price_per_vote,
+        },
+    )
+}
+
+pub fn initialize_operator_session(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    owner: Pubkey,
+    operator: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    let user = pda::user(nft_mint);
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::InitializeOperatorSession {
+            forum: pda::forum(forum_name),
+            mint,
+            user,
+            operator_session: pda::operator_session(&user),
+            vault_token_account: pda::session_vault(&user, &mint, &token_program),
+            owner,
+            nft_mint: *nft_mint,
+            token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::InitializeOperatorSession {
+            forum_name: forum_name.to_string(),
+            operator,
+        },
+    )
+}
+
+pub fn deposit(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    owner: Pubkey,
+    operator: Pubkey,
+    amount: u64,
+    token_program: Pubkey,
+) -> Instruction {
+    let user = pda::user(nft_mint);
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::Deposit {
+            forum: pda::forum(forum_name),
+            mint,
+            user,
+            operator: pda::operator(&operator),
+            user_token_account: pda::associated_token(&owner, &mint, &token_program),
+            operator_session: pda::operator_session(&user),
+            vault_token_account: pda::session_vault(&user, &mint, &token_program),
+            owner,
+            nft_mint: *nft_mint,
+            token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::Deposit {
+            forum_name: forum_name.to_string(),
+            operator,
+            amount,
+        },
+    )
+}
+
+pub fn withdraw(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    owner: Pubkey,
+    operator: Pubkey,
+    amount: u64,
+    token_program: Pubkey,
+) -> Instruction {
+    let user = pda::user(nft_mint);
+    let mint = pda::mint();
+
+    build(
+        neobots::accounts::Withdraw {
+            forum: pda::forum(forum_name),
+            mint,
+            user,
+            operator: pda::operator(&operator),
+            user_token_account: pda::associated_token(&owner, &mint, &token_program),
+            operator_session: pda::operator_session(&user),
+            vault_token_account: pda::session_vault(&user, &mint, &token_program),
+            owner,
+            nft_mint: *nft_mint,
+            token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::Withdraw {
+            forum_name: forum_name.to_string(),
+            operator,
+            amount,
+        },
+    )
+}
+
+pub fn set_operator(
+    forum_name: &str,
+    nft_mint: &Pubkey,
+    owner: Pubkey,
+    operator: Pubkey,
+) -> Instruction {
+    let user = pda::user(nft_mint);
+
+    build(
+        neobots::accounts::SetOperator {
+            forum: pda::forum(forum_name),
+            user,
+            operator: pda::operator(&operator),
+            operator_session: pda::operator_session(&user),
+            owner,
+            nft_mint: *nft_mint,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::SetOperator {
+            forum_name: forum_name.to_string(),
+            operator,
+        },
+    )
+}
+
+// == Migrations ==
+
+/// Upgrades `account` to the current layout, paid by `authority` (the forum
+/// admin or the owner of the account). Users also need `nft_mint`, their NFT.
+/// Posts and operator sessions also need `user`, their author or user account,
+/// migrated first.
+pub fn migrate_account(
+    forum_name: &str,
+    account: Pubkey,
+    user: Option<Pubkey>,
+    nft_mint: Option<Pubkey>,
+    authority: Pubkey,
+) -> Instruction {
+    build(
+        neobots::accounts::MigrateAccount {
+            forum: pda::forum(forum_name),
+            account,
+            user,
+            nft_mint,
+            authority,
+            system_program: system_program::ID,
+        },
+        neobots::instruction::MigrateAccount {
+            forum_name: forum_name.to_string(),
+        },
+    )
+}
@@ -0,0 +1,16 @@
+//! Client side of the neobots program.
+//!
+//! - [`pda`]: addresses of every program account, from the same seeds as the program.
+//! - [`instructions`]: one builder per program instruction.
+//! - [`accounts`]: decoding and RPC fetch helpers for the program accounts.
+//! - [`rewards`]: off-chain replica of the reward and claim math.
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+pub mod rewards;
+
+pub use neobots::ID as PROGRAM_ID;
+
+/// Name of the forum, the program only accepts this one for now.
+pub const FORUM_NAME: &str = "forum_id";
@@ -0,0 +1,86 @@
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &neobots::ID).0
+}
+
+pub fn forum(forum_name: &str) -> Pubkey {
+    find(&[b"forum", forum_name.as_bytes()])
+}
+
+/// Reward token mint, shared by every forum of the program.
+pub fn mint() -> Pubkey {
+    find(&[b"mint"])
+}
+
+pub fn user_counter() -> Pubkey {
+    find(&[b"usercounter"])
+}
+
+pub fn operator_pool() -> Pubkey {
+    find(&[b"operatorpool"])
+}
+
+pub fn round_history(forum: &Pubkey) -> Pubkey {
+    find(&[b"roundhistory", forum.as_ref()])
+}
+
+/// User bound to a bot NFT.
+pub fn user(nft_mint: &Pubkey) -> Pubkey {
+    find(&[b"user", nft_mint.as_ref()])
+}
+
+/// Post number `sequence` of `user` (the user account, not the NFT).
+pub fn post(forum: &Pubkey, user: &Pubkey, sequence: u32) -> Pubkey {
+    find(&[
+        b"post",
+        forum.as_ref(),
+        user.as_ref(),
+        sequence.to_le_bytes().as_ref(),
+    ])
+}
+
+pub fn tag(forum: &Pubkey, tag_name: &str) -> Pubkey {
+    find(&[b"tag", forum.as_ref(), tag_name.as_bytes()])
+}
+
+/// Operator registered by the `operator` wallet.
+pub fn operator(operator: &Pubkey) -> Pubkey {
+    find(&[b"operator", operator.as_ref()])
+}
+
+pub fn operator_session(user: &Pubkey) -> Pubkey {
+    find(&[b"operatorsession", user.as_ref()])
+}
+
+pub fn vesting(nft_mint: &Pubkey) -> Pubkey {
+    find(&[b"vesting", nft_mint.as_ref()])
+}
+
+/// Metaplex metadata of the legacy SPL mint.
+pub fn token_metadata(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            anchor_spl::metadata::ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &anchor_spl::metadata::ID,
+    )
+    .0
+}
+
+pub fn associated_token(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
+
+/// Token vault of an operator session.
+pub fn session_vault(user: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    associated_token(&operator_session(user), mint, token_program)
+}
+
+/// Token vault holding the vested rewards of a bot.
+pub fn vesting_vault(nft_mint: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    associated_token(&vesting(nft_mint), mint, token_program)
+}
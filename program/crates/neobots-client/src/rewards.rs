@@ -0,0 +1,67 @@
+//! Reward math of the program applied to fetched accounts, to show what a
+//! user earns and can claim without sending a transaction. The formulas come
+//! from `neobots-economics`, like on-chain.
+
+use neobots::{Forum, User};
+use neobots_economics::{claim_amount, is_reward_expired, mintable_amount, round_budget};
+
+/// Reward of an action with weight `k`, same as `calculate_reward` on-chain.
+/// `None` when the instruction would fail with `MathOverflow`.
+pub fn calculate_reward(forum: &Forum, k: u64) -> Option<u64> {
+    neobots_economics::calculate_reward(k, forum.round_status.round_distribution_rate)
+}
+
+/// Rewards left unclaimed past the claim window, forfeited by the next
+/// instruction touching the user (or by `expire_rewards`). Saturates instead
+/// of overflowing on corrupted buckets.
+pub fn expired_amount(forum: &Forum, user: &User) -> u64 {
+    user.reward_buckets
+        .iter()
+        .filter(|bucket| {
+            is_reward_expired(
+                bucket.round_number,
+                forum.round_config.claim_window_rounds,
+                forum.round_status.round_number,
+            )
+        })
+        .fold(0u64, |total, bucket| total.saturating_add(bucket.amount))
+}
+
+pub fn has_expired_rewards(forum: &Forum, user: &User) -> bool {
+    expired_amount(forum, user) > 0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimEstimate {
+    /// Amount minted by a `claim` sent now.
+    pub amount: u64,
+    /// Claimable amount left on the user after the claim.
+    pub remaining: u64,
+    /// Forfeited before the claim.
+    pub forfeited: u64,
+    /// The amount goes to the vesting vault instead of the beneficiary.
+    pub vested: bool,
+}
+
+/// What `claim` would mint right now, `mint_supply` is the current supply of the reward mint.
+/// The claim is bounded by the round budget and by the max supply, like on-chain.
+pub fn claimable_now(forum: &Forum, user: &User, mint_supply: u64) -> ClaimEstimate {
+    let forfeited = expired_amount(forum, user);
+    let claimable = user.claimable_amount.saturating_sub(forfeited);
+
+    let amount = claim_amount(
+        claimable,
+        round_budget(
+            forum.round_status.round_max_distribution,
+            forum.round_distributed,
+        ),
+        mintable_amount(forum.supply_config.max_supply, mint_supply),
+    );
+
+    ClaimEstimate {
+        amount,
+        remaining: claimable - amount,
+        forfeited,
+        vested: forum.round_config.vesting_duration > 0,
+    }
+}
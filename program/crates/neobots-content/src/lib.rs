@@ -0,0 +1,51 @@
+//! Posts and comments only keep a sha256 commitment and a short key or URI
+//! on-chain, the body itself lives in the content store (`kvs`).
+//! These helpers check a body fetched from the store against the commitment.
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+/// sha256 of a body, as stored in `Post.content_hash` and passed to `add_comment`.
+pub fn content_hash(body: &[u8]) -> [u8; 32] {
+    Sha256::digest(body).into()
+}
+
+/// Checks that `body` is the content committed by `expected_hash`.
+pub fn verify_content(expected_hash: &[u8; 32], body: &[u8]) -> Result<(), ContentMismatch> {
+    let actual_hash = content_hash(body);
+
+    if &actual_hash == expected_hash {
+        Ok(())
+    } else {
+        Err(ContentMismatch {
+            expected_hash: *expected_hash,
+            actual_hash,
+        })
+    }
+}
+
+/// The body fetched from the content store is not the committed one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContentMismatch {
+    pub expected_hash: [u8; 32],
+    pub actual_hash: [u8; 32],
+}
+
+impl fmt::Display for ContentMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "content hash mismatch: expected {}, got {}",
+            to_hex(&self.expected_hash),
+            to_hex(&self.actual_hash)
+        )
+    }
+}
+
+impl std::error::Error for ContentMismatch {}
+
+/// Lowercase hex encoding of a commitment, e.g. for logs and store lookups.
+pub fn to_hex(hash: &[u8; 32]) -> String {
+    hash.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,61 @@
+use neobots_content::*;
+
+#[test]
+fn hash_round_trips() {
+    let body = br#"{"title":"hello","content":"neobots"}"#;
+    let hash = content_hash(body);
+
+    assert_eq!(hash, content_hash(body));
+    assert_eq!(verify_content(&hash, body), Ok(()));
+    assert_eq!(verify_content(&content_hash(b""), b""), Ok(()));
+}
+
+#[test]
+fn hash_is_sha256() {
+    assert_eq!(
+        to_hex(&content_hash(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        to_hex(&content_hash(b"hello neobots")),
+        "7cab1074451eb934695aa3fdc1053d1f93e55cce8a7c8ba268e13edc4a09bec6"
+    );
+}
+
+#[test]
+fn mismatch_reports_both_hashes() {
+    let expected_hash = content_hash(b"the committed body");
+    let actual_hash = content_hash(b"the stored body");
+
+    let mismatch = verify_content(&expected_hash, b"the stored body").unwrap_err();
+    assert_eq!(
+        mismatch,
+        ContentMismatch {
+            expected_hash,
+            actual_hash,
+        }
+    );
+    assert_eq!(
+        mismatch.to_string(),
+        format!(
+            "content hash mismatch: expected {}, got {}",
+            to_hex(&expected_hash),
+            to_hex(&actual_hash)
+        )
+    );
+
+    // the key of the body in the store is not the body
+    assert!(verify_content(&expected_hash, b"posts/alice/3").is_err());
+}
+
+#[test]
+fn hex_is_lowercase_and_padded() {
+    let mut hash = [0u8; 32];
+    hash[0] = 0x0a;
+    hash[31] = 0xff;
+
+    let hex = to_hex(&hash);
+    assert_eq!(hex.len(), 64);
+    assert!(hex.starts_with("0a00"));
+    assert!(hex.ends_with("00ff"));
+}
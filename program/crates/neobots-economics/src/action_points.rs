@@ -0,0 +1,13 @@
+/// Action points spent by one post, comment or reaction.
+pub const ACTION_POINT_COST: u64 = 1;
+
+/// Action points left after one action, `None` when there are not enough.
+pub fn spend_action_point(available: u64) -> Option<u64> {
+    available.checked_sub(ACTION_POINT_COST)
+}
+
+/// Whether the action points of a user acting in `round_number` must be
+/// reset to the defaults of the forum.
+pub fn needs_action_point_reset(local_round_number: u64, round_number: u64) -> bool {
+    local_round_number < round_number
+}
@@ -0,0 +1,27 @@
+use crate::RATIO_SCALE;
+
+/// Emission of round `round_number` when it halves every `interval_rounds`.
+pub fn halving_emission(
+    initial_round_emission: u64,
+    round_number: u64,
+    interval_rounds: u64,
+) -> u64 {
+    let halvings = round_number / interval_rounds;
+
+    if halvings >= u64::BITS as u64 {
+        0
+    } else {
+        initial_round_emission >> halvings
+    }
+}
+
+/// Emission of the round following one of `previous_round_emission`,
+/// decreased by `rate` / RATIO_SCALE.
+pub fn decay_emission(previous_round_emission: u64, rate: u64) -> u64 {
+    (previous_round_emission as u128 * (RATIO_SCALE - rate) as u128 / RATIO_SCALE as u128) as u64
+}
+
+/// Whether a decay rate is a valid ratio.
+pub fn is_valid_decay_rate(rate: u64) -> bool {
+    rate <= RATIO_SCALE
+}
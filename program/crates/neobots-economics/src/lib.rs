@@ -0,0 +1,26 @@
+//! Every formula deciding what the forum pays: rewards, claims, emission,
+//! vesting, action points and round durations.
+//!
+//! The program calls these functions, so off-chain code (the client, the
+//! simulator) computing with them gets exactly what the program pays.
+//! Everything works on plain integers, amounts are in base units of the
+//! reward token and ratios are scaled by [`RATIO_SCALE`].
+
+#![no_std]
+
+mod action_points;
+mod emission;
+mod rate;
+mod reward;
+mod round;
+mod vesting;
+
+pub use action_points::*;
+pub use emission::*;
+pub use rate::*;
+pub use reward::*;
+pub use round::*;
+pub use vesting::*;
+
+/// Fixed point scale of the ratios, `RATIO_SCALE` is 1x.
+pub const RATIO_SCALE: u64 = 1_000_000;
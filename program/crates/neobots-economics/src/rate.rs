@@ -0,0 +1,27 @@
+use crate::RATIO_SCALE;
+
+/// Distribution rate making the rewards of the next round match its budget:
+/// `round_max_distribution` / `round_distributed`, clamped to the configured
+/// range. A round without claims keeps the rate at 1x.
+///
+/// The program still carries the rate over from round to round (clamped to
+/// the configured range), this is the adjustment it is meant to switch to.
+pub fn distribution_rate(
+    round_max_distribution: u64,
+    round_distributed: u64,
+    min_rate: u64,
+    max_rate: u64,
+) -> u64 {
+    let rate = if round_distributed == 0 {
+        RATIO_SCALE
+    } else {
+        (round_max_distribution as u128 * RATIO_SCALE as u128 / round_distributed as u128)
+            .min(u64::MAX as u128) as u64
+    };
+
+    clamp_rate(rate, min_rate, max_rate)
+}
+
+pub fn clamp_rate(rate: u64, min_rate: u64, max_rate: u64) -> u64 {
+    rate.max(min_rate).min(max_rate)
+}
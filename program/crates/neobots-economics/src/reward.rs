@@ -0,0 +1,43 @@
+use crate::RATIO_SCALE;
+
+/// Reward of an action with weight `k` at the distribution rate of the round,
+/// `None` when it does not fit in a u64.
+pub fn calculate_reward(k: u64, round_distribution_rate: u64) -> Option<u64> {
+    // k * round_distribution_rate / RATIO_SCALE
+    let reward = k as u128 * round_distribution_rate as u128 / RATIO_SCALE as u128;
+
+    u64::try_from(reward).ok()
+}
+
+/// Budget left in the round for claims.
+pub fn round_budget(round_max_distribution: u64, round_distributed: u64) -> u64 {
+    round_max_distribution.saturating_sub(round_distributed)
+}
+
+/// Amount that can still be minted under the max supply.
+pub fn mintable_amount(max_supply: u64, supply: u64) -> u64 {
+    max_supply.saturating_sub(supply)
+}
+
+/// Amount minted by a claim, bounded by the round budget and by the max
+/// supply. Whatever is left stays claimable.
+pub fn claim_amount(claimable_amount: u64, round_budget: u64, mintable_amount: u64) -> u64 {
+    claimable_amount.min(round_budget).min(mintable_amount)
+}
+
+/// Reward of the crank advancing a round, out of the budget left in the
+/// finished round and within the max supply.
+pub fn crank_reward(crank_reward: u64, round_budget: u64, mintable_amount: u64) -> u64 {
+    crank_reward.min(round_budget).min(mintable_amount)
+}
+
+/// Whether rewards earned in `bucket_round_number` are past the claim window
+/// in `round_number`. A zero claim window never expires.
+pub fn is_reward_expired(
+    bucket_round_number: u64,
+    claim_window_rounds: u64,
+    round_number: u64,
+) -> bool {
+    claim_window_rounds > 0
+        && bucket_round_number.saturating_add(claim_window_rounds) < round_number
+}
@@ -0,0 +1,45 @@
+use crate::RATIO_SCALE;
+
+/// Round duration growing linearly from `min_duration` to `max_duration`
+/// with the active users, capped at `users_at_max`.
+pub fn linear_round_duration(
+    min_duration: u64,
+    max_duration: u64,
+    users_at_max: u64,
+    active_users: u64,
+) -> u64 {
+    let users = active_users.min(users_at_max);
+    let position = users as u128 * RATIO_SCALE as u128 / users_at_max as u128;
+
+    interpolate_duration(min_duration, max_duration, position)
+}
+
+/// Same as [`linear_round_duration`] on a log2 scale, the duration grows fast
+/// with the first users and slowly afterwards.
+pub fn log_round_duration(
+    min_duration: u64,
+    max_duration: u64,
+    users_at_max: u64,
+    active_users: u64,
+) -> u64 {
+    let users = active_users.min(users_at_max);
+    // log2(0) is undefined, the curve starts at one user
+    let position = log2_scaled(users.saturating_add(1)) as u128 * RATIO_SCALE as u128
+        / log2_scaled(users_at_max.saturating_add(1)) as u128;
+
+    interpolate_duration(min_duration, max_duration, position)
+}
+
+// `position` between min and max, scaled by RATIO_SCALE
+fn interpolate_duration(min_duration: u64, max_duration: u64, position: u128) -> u64 {
+    min_duration + ((max_duration - min_duration) as u128 * position / RATIO_SCALE as u128) as u64
+}
+
+/// log2(x) scaled by RATIO_SCALE, the fractional part is linearly interpolated.
+pub fn log2_scaled(x: u64) -> u64 {
+    let exponent = x.ilog2();
+    let base = 1u64 << exponent;
+    let fraction = ((x - base) as u128 * RATIO_SCALE as u128 / base as u128) as u64;
+
+    exponent as u64 * RATIO_SCALE + fraction
+}
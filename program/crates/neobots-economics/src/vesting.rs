@@ -0,0 +1,55 @@
+/// Amount of a schedule unlocked at `now` (linear release).
+pub fn vested_amount(total_amount: u64, start_time: i64, duration: u64, now: i64) -> u64 {
+    // i128 holds any difference of two i64
+    let elapsed = now as i128 - start_time as i128;
+
+    if elapsed <= 0 {
+        return 0;
+    }
+
+    if duration == 0 || elapsed >= duration as i128 {
+        return total_amount;
+    }
+
+    (total_amount as u128 * elapsed as u128 / duration as u128) as u64
+}
+
+/// Releasable and still locked parts of a schedule at `now`, `None` when more
+/// than the total was released.
+pub fn vesting_split(
+    total_amount: u64,
+    released_amount: u64,
+    start_time: i64,
+    duration: u64,
+    now: i64,
+) -> Option<(u64, u64)> {
+    let releasable =
+        vested_amount(total_amount, start_time, duration, now).checked_sub(released_amount)?;
+    let locked = total_amount
+        .checked_sub(released_amount)?
+        .checked_sub(releasable)?;
+
+    Some((releasable, locked))
+}
+
+/// Duration of the schedule started at `now` by a claim. A claim never unlocks
+/// faster than `vesting_duration`: the schedule ends at the later of the end of
+/// the running schedule and `now + vesting_duration`, so the `locked` amount of
+/// a running schedule is released with the claim. `None` when the end time
+/// does not fit in an i64.
+pub fn vesting_schedule_duration(
+    locked: u64,
+    start_time: i64,
+    duration: u64,
+    vesting_duration: u64,
+    now: i64,
+) -> Option<u64> {
+    let end_time = start_time.checked_add(i64::try_from(duration).ok()?)?;
+    let remaining = if locked > 0 && end_time > now {
+        u64::try_from(end_time.checked_sub(now)?).ok()?
+    } else {
+        0
+    };
+
+    Some(remaining.max(vesting_duration))
+}
@@ -0,0 +1,115 @@
+use neobots_economics::*;
+
+#[test]
+fn reward_fits_or_fails() {
+    assert_eq!(calculate_reward(u64::MAX, RATIO_SCALE), Some(u64::MAX));
+    assert_eq!(calculate_reward(u64::MAX, RATIO_SCALE + 1), None);
+    assert_eq!(calculate_reward(u64::MAX, u64::MAX), None);
+    assert_eq!(
+        calculate_reward(u64::MAX, RATIO_SCALE / 2),
+        Some(u64::MAX / 2)
+    );
+    assert_eq!(calculate_reward(0, u64::MAX), Some(0));
+    assert_eq!(calculate_reward(1, RATIO_SCALE - 1), Some(0));
+}
+
+#[test]
+fn claim_bounds() {
+    assert_eq!(round_budget(0, u64::MAX), 0);
+    assert_eq!(mintable_amount(u64::MAX, 0), u64::MAX);
+    assert_eq!(mintable_amount(1, u64::MAX), 0);
+    assert_eq!(claim_amount(u64::MAX, u64::MAX, 1), 1);
+    assert_eq!(crank_reward(u64::MAX, u64::MAX, 1), 1);
+    assert_eq!(crank_reward(u64::MAX, 2, u64::MAX), 2);
+    assert_eq!(crank_reward(3, u64::MAX, u64::MAX), 3);
+}
+
+#[test]
+fn reward_expiry() {
+    assert!(!is_reward_expired(0, 0, u64::MAX));
+    assert!(!is_reward_expired(u64::MAX, u64::MAX, u64::MAX));
+    assert!(!is_reward_expired(1, 1, 2));
+    assert!(is_reward_expired(1, 1, 3));
+}
+
+#[test]
+fn emission_schedules() {
+    assert_eq!(halving_emission(u64::MAX, u64::MAX, 1), 0);
+    assert_eq!(halving_emission(u64::MAX, 63, 1), 1);
+    assert_eq!(halving_emission(u64::MAX, 64, 1), 0);
+    assert_eq!(halving_emission(u64::MAX, 0, u64::MAX), u64::MAX);
+
+    assert_eq!(decay_emission(u64::MAX, 0), u64::MAX);
+    assert_eq!(decay_emission(u64::MAX, RATIO_SCALE), 0);
+    assert!(is_valid_decay_rate(RATIO_SCALE));
+    assert!(!is_valid_decay_rate(RATIO_SCALE + 1));
+}
+
+#[test]
+fn distribution_rate_bounds() {
+    let (min_rate, max_rate) = (RATIO_SCALE / 10, RATIO_SCALE * 10);
+
+    assert_eq!(
+        distribution_rate(u64::MAX, 0, min_rate, max_rate),
+        RATIO_SCALE
+    );
+    assert_eq!(distribution_rate(u64::MAX, 1, min_rate, max_rate), max_rate);
+    assert_eq!(distribution_rate(u64::MAX, 1, 0, u64::MAX), u64::MAX);
+    assert_eq!(distribution_rate(0, u64::MAX, min_rate, max_rate), min_rate);
+}
+
+#[test]
+fn vesting_edges() {
+    assert_eq!(
+        vested_amount(u64::MAX, i64::MIN, u64::MAX, i64::MAX),
+        u64::MAX
+    );
+    assert_eq!(vested_amount(u64::MAX, i64::MAX, 1, i64::MIN), 0);
+    assert_eq!(vested_amount(u64::MAX, 0, 0, 1), u64::MAX);
+    assert_eq!(vested_amount(u64::MAX, 0, 2, 1), u64::MAX / 2);
+
+    assert_eq!(vesting_split(100, 0, 0, 100, 50), Some((50, 50)));
+    assert_eq!(vesting_split(100, 60, 0, 100, 50), None);
+    assert_eq!(vesting_split(100, 101, 0, 0, 1), None);
+
+    // a claim gets the full duration, a running schedule ending later keeps its end
+    assert_eq!(
+        vesting_schedule_duration(50, 0, 100, 1_000, 40),
+        Some(1_000)
+    );
+    assert_eq!(vesting_schedule_duration(50, 0, 100, 10, 40), Some(60));
+    assert_eq!(vesting_schedule_duration(0, 0, 100, 10, 40), Some(10));
+    assert_eq!(
+        vesting_schedule_duration(50, 0, 100, 1_000, 100),
+        Some(1_000)
+    );
+    assert_eq!(vesting_schedule_duration(50, i64::MAX, 1, 1_000, 0), None);
+}
+
+#[test]
+fn action_points() {
+    assert_eq!(spend_action_point(0), None);
+    assert_eq!(spend_action_point(1), Some(0));
+    assert_eq!(spend_action_point(u64::MAX), Some(u64::MAX - 1));
+
+    assert!(needs_action_point_reset(0, 1));
+    assert!(!needs_action_point_reset(u64::MAX, u64::MAX));
+}
+
+#[test]
+fn round_durations() {
+    assert_eq!(
+        linear_round_duration(1, u64::MAX, u64::MAX, u64::MAX),
+        u64::MAX
+    );
+    assert_eq!(linear_round_duration(1, u64::MAX, 1, u64::MAX), u64::MAX);
+    assert_eq!(linear_round_duration(10, 20, 10, 0), 10);
+    assert_eq!(
+        log_round_duration(1, u64::MAX, u64::MAX, u64::MAX),
+        u64::MAX
+    );
+    assert_eq!(log_round_duration(10, 20, 10, 0), 10);
+
+    assert_eq!(log2_scaled(1), 0);
+    assert_eq!(log2_scaled(u64::MAX), 63 * RATIO_SCALE + RATIO_SCALE - 1);
+}
@@ -0,0 +1,133 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{anyhow, Context, Result};
+use log::warn;
+use neobots::{Forum, User};
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    account::from_account,
+    clock::Clock,
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    sysvar,
+    transaction::Transaction,
+};
+
+/// What the keeper needs from the cluster.
+pub trait Chain {
+    /// Key paying the fees, it also receives the crank reward.
+    fn payer(&self) -> Pubkey;
+
+    fn clock(&self) -> Result<Clock>;
+
+    fn forum(&self, forum: &Pubkey) -> Result<Forum>;
+
+    /// Program owning `mint`, i.e. the legacy SPL token program or Token-2022.
+    fn token_program(&self, mint: &Pubkey) -> Result<Pubkey>;
+
+    /// Every `User` account of the program that decodes with the current
+    /// layout, see [`decode_users`].
+    fn users(&self) -> Result<Vec<(Pubkey, User)>>;
+
+    /// Signs with the payer, sends and confirms.
+    fn send(&self, instructions: &[Instruction]) -> Result<Signature>;
+}
+
+pub struct RpcChain {
+    client: RpcClient,
+    payer: Keypair,
+}
+
+impl RpcChain {
+    pub fn new(rpc_url: &str, payer: Keypair) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed()),
+            payer,
+        }
+    }
+}
+
+impl Chain for RpcChain {
+    fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    fn clock(&self) -> Result<Clock> {
+        let account = self.client.get_account(&sysvar::clock::ID)?;
+
+        from_account(&account).ok_or_else(|| anyhow!("cannot decode the clock sysvar"))
+    }
+
+    fn forum(&self, forum: &Pubkey) -> Result<Forum> {
+        let data = self
+            .client
+            .get_account_data(forum)
+            .with_context(|| format!("cannot fetch forum {}", forum))?;
+
+        Ok(Forum::try_deserialize(&mut data.as_slice())?)
+    }
+
+    fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        Ok(self.client.get_account(mint)?.owner)
+    }
+
+    fn users(&self) -> Result<Vec<(Pubkey, User)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                0,
+                User::DISCRIMINATOR.to_vec(),
+            ))]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(solana_account_decoder::UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let accounts = self
+            .client
+            .get_program_accounts_with_config(&neobots::ID, config)?;
+
+        Ok(decode_users(
+            accounts
+                .into_iter()
+                .map(|(key, account)| (key, account.data)),
+        ))
+    }
+
+    fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let blockhash = self.client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        Ok(self.client.send_and_confirm_transaction(&transaction)?)
+    }
+}
+
+/// Decodes the data of `User` accounts. An account that does not decode, such
+/// as a user still in its legacy layout, is logged and skipped so that it does
+/// not stop the keeper.
+pub fn decode_users(accounts: impl IntoIterator<Item = (Pubkey, Vec<u8>)>) -> Vec<(Pubkey, User)> {
+    accounts
+        .into_iter()
+        .filter_map(
+            |(key, data)| match User::try_deserialize(&mut data.as_slice()) {
+                Ok(user) => Some((key, user)),
+                Err(err) => {
+                    warn!("skipping user {}: {}", key, err);
+                    None
+                }
+            },
+        )
+        .collect()
+}
@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Keeper settings, read from a TOML file (see `keeper.example.toml`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct KeeperConfig {
+    pub rpc_url: String,
+    pub keypair_path: String,
+    pub forum_name: String,
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default)]
+    pub advance: AdvanceConfig,
+    #[serde(default)]
+    pub maintenance: MaintenanceConfig,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AdvanceConfig {
+    pub max_retries: u32,
+    pub retry_backoff_ms: u64,
+    /// Priority fee in micro-lamports per compute unit, 0 disables it.
+    pub priority_fee_micro_lamports: u64,
+    pub compute_unit_limit: u32,
+}
+
+impl Default for AdvanceConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            retry_backoff_ms: 500,
+            priority_fee_micro_lamports: 0,
+            compute_unit_limit: 200_000,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct MaintenanceConfig {
+    /// Forfeit rewards left unclaimed past the claim window after each advance.
+    #[serde(default)]
+    pub expire_rewards: bool,
+}
+
+fn default_poll_interval_secs() -> u64 {
+    5
+}
+
+impl KeeperConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read keeper config {}", path.display()))?;
+
+        Self::parse(&raw)
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        toml::from_str(raw).context("invalid keeper config")
+    }
+
+    /// `keypair_path` with a leading `~` expanded to the home directory.
+    pub fn keypair_path(&self) -> PathBuf {
+        match (
+            self.keypair_path.strip_prefix("~/"),
+            std::env::var_os("HOME"),
+        ) {
+            (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+            _ => PathBuf::from(&self.keypair_path),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::{thread, time::Duration};
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use neobots::Forum;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, pubkey::Pubkey,
+    signature::Signature,
+};
+
+use neobots_client::{instructions, pda, rewards::has_expired_rewards};
+
+use crate::{chain::Chain, config::KeeperConfig};
+
+pub struct Keeper<C> {
+    chain: C,
+    config: KeeperConfig,
+    forum: Pubkey,
+}
+
+impl<C: Chain> Keeper<C> {
+    pub fn new(chain: C, config: KeeperConfig) -> Self {
+        let forum = pda::forum(&config.forum_name);
+
+        Self {
+            chain,
+            config,
+            forum,
+        }
+    }
+
+    pub fn chain(&self) -> &C {
+        &self.chain
+    }
+
+    /// Runs until an unrecoverable error, polling the forum between rounds.
+    pub fn run(&self) -> Result<()> {
+        loop {
+            let wait = match self.tick() {
+                Ok(wait) => wait,
+                Err(err) => {
+                    warn!("keeper tick failed: {:#}", err);
+                    self.poll_interval()
+                }
+            };
+
+            thread::sleep(wait);
+        }
+    }
+
+    /// Advances the round if it ended and returns how long to wait before the next tick.
+    pub fn tick(&self) -> Result<Duration> {
+        let forum = self.chain.forum(&self.forum)?;
+        let now = self.chain.clock()?.unix_timestamp;
+
+        let remaining = seconds_until_round_end(&forum, now).ok_or_else(|| {
+            anyhow!(
+                "end of round {} does not fit in a timestamp",
+                forum.round_status.round_number
+            )
+        })?;
+        if remaining > 0 {
+            return Ok(Duration::from_secs(remaining as u64).min(self.poll_interval()));
+        }
+
+        if let Some(signature) = self.advance_round(&forum)? {
+            info!(
+                "advanced round {} -> {}: {}",
+                forum.round_status.round_number,
+                forum.round_status.round_number + 1,
+                signature
+            );
+
+            if self.config.maintenance.expire_rewards {
+                self.expire_rewards()?;
+            }
+        }
+
+        Ok(Duration::ZERO)
+    }
+
+    /// Sends `advance_round`, retrying with exponential backoff. Returns `None` when
+    /// another crank advanced the round first.
+    pub fn advance_round(&self, forum: &Forum) -> Result<Option<Signature>> {
+        let token_program = self.chain.token_program(&forum.mint)?;
+        let instruction =
+            instructions::advance_round(&self.config.forum_name, self.chain.payer(), token_program);
+        let instructions = self.with_compute_budget(instruction);
+
+        let mut attempt = 0;
+        loop {
+            match self.chain.send(&instructions) {
+                Ok(signature) => return Ok(Some(signature)),
+                Err(err) => {
+                    let current = self.chain.forum(&self.forum)?;
+                    if current.round_status.round_number > forum.round_status.round_number {
+                        info!("round already advanced by another crank");
+                        return Ok(None);
+                    }
+
+                    if attempt >= self.config.advance.max_retries {
+                        return Err(err);
+                    }
+
+                    let backoff = self.config.advance.retry_backoff_ms << attempt;
+                    warn!(
+                        "advance_round attempt {} failed, retrying in {}ms: {:#}",
+                        attempt + 1,
+                        backoff,
+                        err
+                    );
+                    thread::sleep(Duration::from_millis(backoff));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Forfeits the expired rewards of every user holding some, one transaction per user.
+    pub fn expire_rewards(&self) -> Result<usize> {
+        let forum = self.chain.forum(&self.forum)?;
+        let mut expired = 0;
+
+        for (key, user) in self.chain.users()? {
+            if !has_expired_rewards(&forum, &user) {
+                continue;
+            }
+
+            let instruction = instructions::expire_rewards(
+                &self.config.forum_name,
+                &user.nft_mint,
+                self.chain.payer(),
+            );
+
+            match self.chain.send(&self.with_compute_budget(instruction)) {
+                Ok(signature) => {
+                    info!("expired rewards of {}: {}", key, signature);
+                    expired += 1;
+                }
+                Err(err) => warn!("expire_rewards failed for {}: {:#}", key, err),
+            }
+        }
+
+        Ok(expired)
+    }
+
+    fn with_compute_budget(&self, instruction: Instruction) -> Vec<Instruction> {
+        let advance = &self.config.advance;
+        let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+            advance.compute_unit_limit,
+        )];
+
+        if advance.priority_fee_micro_lamports > 0 {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                advance.priority_fee_micro_lamports,
+            ));
+        }
+
+        instructions.push(instruction);
+        instructions
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.config.poll_interval_secs)
+    }
+}
+
+/// Seconds left in the current round, zero or negative once it can be advanced.
+/// `None` when the end of the round does not fit in an i64.
+pub fn seconds_until_round_end(forum: &Forum, now: i64) -> Option<i64> {
+    let round_duration = i64::try_from(forum.round_status.round_duration).ok()?;
+
+    forum
+        .round_status
+        .round_start_time
+        .checked_add(round_duration)?
+        .checked_sub(now)
+}
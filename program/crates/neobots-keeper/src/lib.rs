@@ -0,0 +1,13 @@
+//! Keeper for the neobots forum: advances rounds as soon as they end and runs
+//! the permissionless maintenance instructions.
+//!
+//! The chain is accessed through [`Chain`] so the keeper logic can run against
+//! an RPC node (`solana-test-validator`, devnet, ...) or an in-process bank.
+
+pub mod chain;
+pub mod config;
+pub mod keeper;
+
+pub use chain::{decode_users, Chain, RpcChain};
+pub use config::KeeperConfig;
+pub use keeper::Keeper;
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use neobots_keeper::{Keeper, KeeperConfig, RpcChain};
+use solana_sdk::signature::read_keypair_file;
+
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let config_path = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("keeper.toml"));
+    let config = KeeperConfig::load(&config_path)?;
+
+    let payer = read_keypair_file(config.keypair_path()).map_err(|err| {
+        anyhow!(
+            "cannot read keypair {}: {}",
+            config.keypair_path().display(),
+            err
+        )
+    })?;
+
+    info!(
+        "keeper for forum {} on {}",
+        config.forum_name, config.rpc_url
+    );
+
+    let chain = RpcChain::new(&config.rpc_url, payer);
+    Keeper::new(chain, config).run()
+}
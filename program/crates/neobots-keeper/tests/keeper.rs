@@ -0,0 +1,250 @@
+use std::{cell::RefCell, future::Future, time::Duration};
+
+use anchor_lang::Discriminator;
+use anyhow::{anyhow, Result};
+use neobots::{Forum, RoundConfig, User, INITIAL_ROUND_CONFIG};
+use neobots_client::FORUM_NAME;
+use neobots_keeper::{decode_users, Chain, Keeper, KeeperConfig};
+use neobots_testkit::{Bot, TestEnv};
+use solana_sdk::{
+    clock::Clock, instruction::Instruction, pubkey::Pubkey, signature::Signature, signer::Signer,
+    transaction::Transaction,
+};
+use tokio::runtime::Runtime;
+
+/// [`Chain`] backed by the BanksClient of a [`TestEnv`], the payer of the
+/// environment is the keeper. The bank cannot list program accounts, so the
+/// users are the ones registered with [`BanksChain::watch`].
+struct BanksChain {
+    runtime: Runtime,
+    env: RefCell<TestEnv>,
+    users: RefCell<Vec<Pubkey>>,
+}
+
+impl BanksChain {
+    fn new(round_config: RoundConfig) -> Self {
+        let runtime = Runtime::new().unwrap();
+        let env = runtime.block_on(TestEnv::with_round_params(Some(round_config), None));
+
+        Self {
+            runtime,
+            env: RefCell::new(env),
+            users: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn watch(&self, user: Pubkey) {
+        self.users.borrow_mut().push(user);
+    }
+}
+
+impl Chain for BanksChain {
+    fn payer(&self) -> Pubkey {
+        self.env.borrow().payer().pubkey()
+    }
+
+    fn clock(&self) -> Result<Clock> {
+        Ok(self.block_on(self.env.borrow_mut().clock()))
+    }
+
+    fn forum(&self, forum: &Pubkey) -> Result<Forum> {
+        Ok(self.block_on(self.env.borrow_mut().account(forum)))
+    }
+
+    fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        let mut env = self.env.borrow_mut();
+        let account = self
+            .block_on(env.context.banks_client.get_account(*mint))?
+            .ok_or_else(|| anyhow!("mint {} not found", mint))?;
+
+        Ok(account.owner)
+    }
+
+    fn users(&self) -> Result<Vec<(Pubkey, User)>> {
+        let mut env = self.env.borrow_mut();
+        let mut accounts = Vec::new();
+
+        for key in self.users.borrow().iter() {
+            let account = self
+                .block_on(env.context.banks_client.get_account(*key))?
+                .ok_or_else(|| anyhow!("user {} not found", key))?;
+            accounts.push((*key, account.data));
+        }
+
+        Ok(decode_users(accounts))
+    }
+
+    fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let mut env = self.env.borrow_mut();
+        let payer = env.payer();
+        let banks_client = &mut env.context.banks_client;
+
+        let blockhash = self.block_on(banks_client.get_latest_blockhash())?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[&payer],
+            blockhash,
+        );
+        let signature = transaction.signatures[0];
+        self.block_on(banks_client.process_transaction(transaction))?;
+
+        Ok(signature)
+    }
+}
+
+fn keeper(chain: BanksChain, expire_rewards: bool) -> Keeper<BanksChain> {
+    let config = KeeperConfig::parse(&format!(
+        r#"
+        rpc_url = "http://127.0.0.1:8899"
+        keypair_path = "keeper.json"
+        forum_name = "{}"
+
+        [advance]
+        max_retries = 0
+        retry_backoff_ms = 0
+        priority_fee_micro_lamports = 0
+        compute_unit_limit = 400000
+
+        [maintenance]
+        expire_rewards = {}
+        "#,
+        FORUM_NAME, expire_rewards
+    ))
+    .unwrap();
+
+    Keeper::new(chain, config)
+}
+
+fn forum(keeper: &Keeper<BanksChain>) -> Forum {
+    let chain = keeper.chain();
+    chain.block_on(chain.env.borrow_mut().forum_account())
+}
+
+#[test]
+fn tick_advances_the_round_once_it_ended() {
+    let keeper = keeper(BanksChain::new(INITIAL_ROUND_CONFIG), false);
+    let chain = keeper.chain();
+
+    let round_duration = forum(&keeper).round_status.round_duration;
+
+    // half of the round is left, the keeper waits
+    chain.block_on(chain.env.borrow_mut().warp(round_duration as i64 / 2));
+    let wait = keeper.tick().unwrap();
+    assert!(wait > Duration::ZERO);
+    assert_eq!(forum(&keeper).round_status.round_number, 0);
+
+    chain.block_on(chain.env.borrow_mut().warp(round_duration as i64));
+    assert_eq!(keeper.tick().unwrap(), Duration::ZERO);
+
+    let forum = forum(&keeper);
+    assert_eq!(forum.round_status.round_number, 1);
+
+    // the keeper is the crank and earns its reward
+    let payer = chain.payer();
+    let balance = chain.block_on(chain.env.borrow_mut().token_balance(&payer));
+    assert_eq!(balance, INITIAL_ROUND_CONFIG.crank_reward);
+}
+
+#[test]
+fn tick_expires_the_rewards_past_the_claim_window() {
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.claim_window_rounds = 1;
+
+    let keeper = keeper(BanksChain::new(round_config), true);
+    let chain = keeper.chain();
+
+    let (alice, bob) = chain.block_on(async {
+        let mut env = chain.env.borrow_mut();
+        let alice = env.create_bot("alice").await;
+        let bob = env.create_bot("bob").await;
+
+        env.create_post(&alice).await.unwrap();
+        env.add_comment(&bob, &alice, 0).await.unwrap();
+
+        (alice, bob)
+    });
+    chain.watch(alice.user);
+    chain.watch(bob.user);
+
+    let user = |bot: &Bot| chain.block_on(chain.env.borrow_mut().user_account(bot));
+    assert_eq!(user(&bob).claimable_amount, INITIAL_ROUND_CONFIG.k_comment);
+
+    let advance = || {
+        let round_duration = forum(&keeper).round_status.round_duration;
+        chain.block_on(chain.env.borrow_mut().warp(round_duration as i64));
+        keeper.tick().unwrap();
+    };
+
+    // still within the window, nothing to expire
+    advance();
+    assert_eq!(keeper.expire_rewards().unwrap(), 0);
+    assert_eq!(user(&bob).claimable_amount, INITIAL_ROUND_CONFIG.k_comment);
+
+    // the tick forfeits the rewards right after advancing
+    advance();
+    assert_eq!(forum(&keeper).round_status.round_number, 2);
+
+    let bob_user = user(&bob);
+    assert_eq!(bob_user.claimable_amount, 0);
+    assert!(bob_user.reward_buckets.is_empty());
+    assert_eq!(user(&alice).claimable_amount, 0);
+    assert_eq!(keeper.expire_rewards().unwrap(), 0);
+}
+
+#[test]
+fn advance_round_skips_a_round_advanced_by_another_crank() {
+    let keeper = keeper(BanksChain::new(INITIAL_ROUND_CONFIG), false);
+    let chain = keeper.chain();
+
+    let stale = forum(&keeper);
+    chain.block_on(chain.env.borrow_mut().advance_round());
+
+    // the forum the keeper read is one round behind, its crank fails and is
+    // not retried
+    assert_eq!(keeper.advance_round(&stale).unwrap(), None);
+    assert_eq!(forum(&keeper).round_status.round_number, 1);
+}
+
+#[test]
+fn tick_skips_the_users_that_do_not_decode() {
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.claim_window_rounds = 1;
+
+    let keeper = keeper(BanksChain::new(round_config), true);
+    let chain = keeper.chain();
+
+    let (alice, bob) = chain.block_on(async {
+        let mut env = chain.env.borrow_mut();
+        let alice = env.create_bot("alice").await;
+        let bob = env.create_bot("bob").await;
+
+        env.create_post(&alice).await.unwrap();
+        env.add_comment(&bob, &alice, 0).await.unwrap();
+
+        // a user of another layout, it does not decode as a User
+        let mut data = User::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0; 16]);
+        env.set_account_data(&alice.user, data).await;
+
+        (alice, bob)
+    });
+    chain.watch(alice.user);
+    chain.watch(bob.user);
+
+    assert_eq!(chain.users().unwrap().len(), 1);
+
+    for _ in 0..2 {
+        let round_duration = forum(&keeper).round_status.round_duration;
+        chain.block_on(chain.env.borrow_mut().warp(round_duration as i64));
+        assert_eq!(keeper.tick().unwrap(), Duration::ZERO);
+    }
+
+    assert_eq!(forum(&keeper).round_status.round_number, 2);
+    let bob_user = chain.block_on(chain.env.borrow_mut().user_account(&bob));
+    assert_eq!(bob_user.claimable_amount, 0);
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Simulation settings, read from a TOML file (see `sim.example.toml`).
+/// Missing fields keep their default.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct SimulationConfig {
+    pub rounds: u64,
+    pub seed: u64,
+    pub initial_bots: u64,
+    pub max_bots: u64,
+    pub growth_target_hours: f64,
+    pub mid_point_hours: f64,
+    pub growth_steepness: f64,
+    pub activity_decay_per_hour: f64,
+    pub min_activity: f64,
+    pub claim_probability: f64,
+    pub usage: UsageConfig,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            rounds: 96,
+            seed: 0,
+            initial_bots: 3,
+            max_bots: 30,
+            growth_target_hours: 8.0,
+            mid_point_hours: 4.0,
+            growth_steepness: 5.0,
+            activity_decay_per_hour: 0.05,
+            min_activity: 0.2,
+            claim_probability: 0.5,
+            usage: UsageConfig::default(),
+        }
+    }
+}
+
+/// Probability of using each kind of action point.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct UsageConfig {
+    pub post: f64,
+    pub comment: f64,
+    pub upvote: f64,
+    pub downvote: f64,
+    pub like: f64,
+    pub banvote: f64,
+}
+
+impl Default for UsageConfig {
+    fn default() -> Self {
+        Self {
+            post: 0.4,
+            comment: 0.7,
+            upvote: 0.6,
+            downvote: 0.3,
+            like: 0.9,
+            banvote: 0.05,
+        }
+    }
+}
+
+impl SimulationConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read simulation config {}", path.display()))?;
+
+        toml::from_str(&raw).context("invalid simulation config")
+    }
+}
@@ -0,0 +1,11 @@
+//! Tokenomics simulator: grows a bot population and has it post, comment,
+//! react and claim against the program in-process, one round after the other.
+
+pub mod config;
+pub mod population;
+pub mod report;
+pub mod simulation;
+
+pub use config::SimulationConfig;
+pub use report::RoundReport;
+pub use simulation::Simulation;
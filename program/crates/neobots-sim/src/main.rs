@@ -0,0 +1,26 @@
+//! Runs a bot population against the program in-process and prints one CSV
+//! row per round on stdout.
+
+use std::{io, path::PathBuf};
+
+use anyhow::Result;
+use log::info;
+use neobots_sim::{Simulation, SimulationConfig};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let config = match std::env::args().nth(1) {
+        Some(path) => SimulationConfig::load(&PathBuf::from(path))?,
+        None => SimulationConfig::default(),
+    };
+
+    info!(
+        "simulating {} rounds with up to {} bots",
+        config.rounds, config.max_bots
+    );
+
+    let mut simulation = Simulation::start(config).await;
+    simulation.run(&mut io::stdout()).await
+}
@@ -0,0 +1,38 @@
+//! Bot population model of `tokenomics/utils.py`.
+
+use rand::Rng;
+
+use crate::config::SimulationConfig;
+
+/// Bots expected after `hours`, on the sigmoid of `simulate_bot_growth`.
+pub fn target_bots(config: &SimulationConfig, hours: f64) -> u64 {
+    let exponent =
+        -config.growth_steepness * (hours - config.mid_point_hours) / config.growth_target_hours;
+    let target = config.max_bots as f64 / (1.0 + exponent.exp());
+
+    (target.round() as u64).max(config.initial_bots)
+}
+
+/// Bots acting after `hours`, like `calculate_active_bots`.
+pub fn active_bots(config: &SimulationConfig, total_bots: usize, hours: f64) -> usize {
+    let activity_rate = config
+        .min_activity
+        .max(1.0 - config.activity_decay_per_hour * hours);
+
+    ((total_bots as f64 * activity_rate) as usize)
+        .max(1)
+        .min(total_bots)
+}
+
+/// Share of its action points a bot uses, drawn around `probability` like
+/// `calculate_ap_usage`.
+pub fn usage_rate<R: Rng>(rng: &mut R, probability: f64) -> f64 {
+    if probability <= 0.0 {
+        return 0.0;
+    }
+
+    let low = (probability - 0.2).max(0.1);
+    let high = (probability + 0.1).min(1.0).max(low);
+
+    rng.gen_range(low..=high)
+}
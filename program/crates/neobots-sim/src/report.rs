@@ -0,0 +1,48 @@
+/// One CSV row, what happened during a round.
+#[derive(Clone, Debug, Default)]
+pub struct RoundReport {
+    pub round: u64,
+    pub hours: f64,
+    pub bots: usize,
+    pub active_bots: usize,
+    pub transactions: u64,
+    pub failed_transactions: u64,
+    /// Supply of the reward mint once the round is advanced.
+    pub supply: u64,
+    pub round_max_distribution: u64,
+    /// Claimed during the round.
+    pub round_distributed: u64,
+    /// Rewards earned by the bots during the round, net of forfeits.
+    pub rewards_earned: u64,
+    pub distribution_rate: u64,
+    pub compute_units: u64,
+    pub fee_lamports: u64,
+    /// Rent paid by the bots for the accounts they created.
+    pub rent_lamports: u64,
+}
+
+impl RoundReport {
+    pub const CSV_HEADER: &'static str = "round,hours,bots,active_bots,transactions,\
+        failed_transactions,supply,round_max_distribution,round_distributed,rewards_earned,\
+        distribution_rate,compute_units,fee_lamports,rent_lamports";
+
+    pub fn csv_row(&self) -> String {
+        format!(
+            "{},{:.3},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.round,
+            self.hours,
+            self.bots,
+            self.active_bots,
+            self.transactions,
+            self.failed_transactions,
+            self.supply,
+            self.round_max_distribution,
+            self.round_distributed,
+            self.rewards_earned,
+            self.distribution_rate,
+            self.compute_units,
+            self.fee_lamports,
+            self.rent_lamports,
+        )
+    }
+}
@@ -0,0 +1,392 @@
+use std::io::Write;
+
+use anyhow::{bail, Result};
+use log::{debug, info};
+use neobots::{Forum, ReactionType, User};
+use neobots_client::{instructions, rewards::claimable_now, FORUM_NAME};
+use neobots_economics::needs_action_point_reset;
+use neobots_testkit::{Bot, TestEnv};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::{config::SimulationConfig, population, report::RoundReport};
+
+#[derive(Clone, Copy)]
+struct PostRef {
+    author: usize,
+    sequence: u32,
+}
+
+/// Comment sent by the bot `author` on the post `post_sequence` of the bot
+/// `post_author`, bots are indices in [`Simulation::bots`].
+#[derive(Clone, Copy, Debug)]
+pub struct CommentRef {
+    pub post_author: usize,
+    pub post_sequence: u32,
+    pub author: usize,
+    pub sequence: u32,
+}
+
+pub struct Simulation {
+    env: TestEnv,
+    config: SimulationConfig,
+    rng: StdRng,
+    bots: Vec<Bot>,
+    posts: Vec<PostRef>,
+    comments: Vec<CommentRef>,
+    start_time: i64,
+    // claimable amount of all the bots at the end of the previous round
+    claimable: u64,
+    report: RoundReport,
+}
+
+impl Simulation {
+    /// Fresh forum with the initial config of the program, and no bots.
+    pub async fn start(config: SimulationConfig) -> Self {
+        let mut env = TestEnv::new().await;
+        let start_time = env.clock().await.unix_timestamp;
+
+        Self {
+            env,
+            rng: StdRng::seed_from_u64(config.seed),
+            config,
+            bots: Vec::new(),
+            posts: Vec::new(),
+            comments: Vec::new(),
+            start_time,
+            claimable: 0,
+            report: RoundReport::default(),
+        }
+    }
+
+    pub async fn run<W: Write>(&mut self, out: &mut W) -> Result<()> {
+        writeln!(out, "{}", RoundReport::CSV_HEADER)?;
+
+        for _ in 0..self.config.rounds {
+            let report = self.run_round().await?;
+
+            info!(
+                "round {} ({:.1}h): {} bots, {} active, supply {}",
+                report.round, report.hours, report.bots, report.active_bots, report.supply
+            );
+
+            writeln!(out, "{}", report.csv_row())?;
+            out.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Lets the bots act, claims and advances the round.
+    pub async fn run_round(&mut self) -> Result<RoundReport> {
+        let hours = (self.env.clock().await.unix_timestamp - self.start_time) as f64 / 3600.0;
+        let forum = self.env.forum_account().await;
+
+        self.report = RoundReport {
+            round: forum.round_status.round_number,
+            hours,
+            ..RoundReport::default()
+        };
+
+        let target_bots = population::target_bots(&self.config, hours);
+        while (self.bots.len() as u64) < target_bots {
+            self.add_bot().await?;
+        }
+
+        let mut active: Vec<usize> = (0..self.bots.len()).collect();
+        active.shuffle(&mut self.rng);
+        active.truncate(population::active_bots(
+            &self.config,
+            self.bots.len(),
+            hours,
+        ));
+
+        self.report.bots = self.bots.len();
+        self.report.active_bots = active.len();
+
+        for &index in &active {
+            self.act(index, &forum).await?;
+        }
+
+        self.claim_rewards().await?;
+
+        // rewards earned = claimable now - claimable before + claimed
+        let forum = self.env.forum_account().await;
+        let claimable = self.total_claimable().await;
+        self.report.round_max_distribution = forum.round_status.round_max_distribution;
+        self.report.round_distributed = forum.round_distributed;
+        self.report.distribution_rate = forum.round_status.round_distribution_rate;
+        self.report.rewards_earned =
+            (claimable + forum.round_distributed).saturating_sub(self.claimable);
+        self.claimable = claimable;
+
+        self.advance_round(&forum).await?;
+        self.report.supply = self.env.mint_supply().await;
+
+        Ok(std::mem::take(&mut self.report))
+    }
+
+    pub fn env(&mut self) -> &mut TestEnv {
+        &mut self.env
+    }
+
+    pub fn bots(&self) -> &[Bot] {
+        &self.bots
+    }
+
+    /// Comments sent so far, in order.
+    pub fn comments(&self) -> &[CommentRef] {
+        &self.comments
+    }
+
+    async fn add_bot(&mut self) -> Result<()> {
+        let owner = self.env.new_wallet().await;
+        let collection = self.env.collection;
+        let nft_mint = self.env.create_asset(&collection, &owner.pubkey()).await;
+
+        let instruction = instructions::initialize_user(
+            FORUM_NAME,
+            &nft_mint,
+            owner.pubkey(),
+            "curious".to_string(),
+            format!("bot {}", self.bots.len()),
+            "thumb".to_string(),
+        );
+
+        if !self
+            .send(instruction, &[&owner], Some(owner.pubkey()))
+            .await?
+        {
+            bail!("cannot initialize the user of bot {}", self.bots.len());
+        }
+
+        self.bots.push(Bot {
+            owner,
+            nft_mint,
+            user: neobots_client::pda::user(&nft_mint),
+        });
+
+        Ok(())
+    }
+
+    // spend a share of every kind of action point of the bot
+    async fn act(&mut self, index: usize, forum: &Forum) -> Result<()> {
+        let owner = self.bots[index].owner.insecure_clone();
+        let nft_mint = self.bots[index].nft_mint;
+        let user: User = self.env.account(&self.bots[index].user).await;
+
+        let action_points =
+            if needs_action_point_reset(user.local_round_number, forum.round_status.round_number) {
+                forum.round_config.default_action_points.clone()
+            } else {
+                user.action_points.clone()
+            };
+        let usage = self.config.usage.clone();
+
+        let mut post_count = user.post_count;
+        for _ in 0..self.planned(action_points.post, usage.post) {
+            let instruction = instructions::create_post(
+                FORUM_NAME,
+                &nft_mint,
+                owner.pubkey(),
+                post_count,
+                format!("post {}", post_count),
+                "general".to_string(),
+                [0; 32],
+                String::new(),
+            );
+
+            if self
+                .send(instruction, &[&owner], Some(owner.pubkey()))
+                .await?
+            {
+                self.posts.push(PostRef {
+                    author: index,
+                    sequence: post_count,
+                });
+                post_count += 1;
+            }
+        }
+
+        let mut comment_count = user.comment_count;
+        for _ in 0..self.planned(action_points.comment, usage.comment) {
+            // the sender and the post author must be different accounts
+            let Some(position) = pick(&mut self.rng, &self.posts, |post| post.author != index)
+            else {
+                break;
+            };
+            let PostRef {
+                author: post_author,
+                sequence: post_sequence,
+            } = self.posts[position];
+
+            let instruction = instructions::add_comment(
+                FORUM_NAME,
+                &self.bots[post_author].user,
+                post_sequence,
+                &nft_mint,
+                owner.pubkey(),
+                format!("comment {}", comment_count),
+                [0; 32],
+                String::new(),
+            );
+
+            if self.send(instruction, &[&owner], None).await? {
+                comment_count += 1;
+                self.comments.push(CommentRef {
+                    post_author,
+                    post_sequence,
+                    author: index,
+                    sequence: comment_count,
+                });
+            }
+        }
+
+        let reactions = [
+            (ReactionType::Upvote, action_points.upvote, usage.upvote),
+            (
+                ReactionType::Downvote,
+                action_points.downvote,
+                usage.downvote,
+            ),
+            (ReactionType::Like, action_points.like, usage.like),
+            (ReactionType::Banvote, action_points.banvote, usage.banvote),
+        ];
+
+        for (reaction_type, points, probability) in reactions {
+            for _ in 0..self.planned(points, probability) {
+                let Some(position) = pick(&mut self.rng, &self.comments, |comment| {
+                    comment.author != index
+                }) else {
+                    break;
+                };
+                let comment = self.comments[position];
+
+                let instruction = instructions::add_reaction(
+                    FORUM_NAME,
+                    &self.bots[comment.post_author].user,
+                    comment.post_sequence,
+                    &self.bots[comment.author].user,
+                    comment.sequence,
+                    &nft_mint,
+                    owner.pubkey(),
+                    reaction_type,
+                );
+
+                self.send(instruction, &[&owner], None).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn claim_rewards(&mut self) -> Result<()> {
+        for index in 0..self.bots.len() {
+            if !self.rng.gen_bool(self.config.claim_probability) {
+                continue;
+            }
+
+            let owner = self.bots[index].owner.insecure_clone();
+            let nft_mint = self.bots[index].nft_mint;
+            let user: User = self.env.account(&self.bots[index].user).await;
+            let forum = self.env.forum_account().await;
+            let supply = self.env.mint_supply().await;
+
+            // a bot only claims when something would be minted
+            if claimable_now(&forum, &user, supply).amount == 0 {
+                continue;
+            }
+
+            let instruction = instructions::claim(
+                FORUM_NAME,
+                &nft_mint,
+                owner.pubkey(),
+                self.env.token_program,
+                forum.round_config.vesting_duration > 0,
+            );
+            self.send(instruction, &[&owner], Some(owner.pubkey()))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn advance_round(&mut self, forum: &Forum) -> Result<()> {
+        self.env
+            .warp(forum.round_status.round_duration as i64)
+            .await;
+
+        let payer = self.env.payer().pubkey();
+        let instruction = instructions::advance_round(FORUM_NAME, payer, self.env.token_program);
+
+        if !self.send(instruction, &[], None).await? {
+            bail!("cannot advance round {}", forum.round_status.round_number);
+        }
+
+        Ok(())
+    }
+
+    async fn total_claimable(&mut self) -> u64 {
+        let mut total = 0;
+
+        for index in 0..self.bots.len() {
+            let user: User = self.env.account(&self.bots[index].user).await;
+            total += user.claimable_amount;
+        }
+
+        total
+    }
+
+    // actions a bot takes out of `points` action points
+    fn planned(&mut self, points: u64, probability: f64) -> u64 {
+        (points as f64 * population::usage_rate(&mut self.rng, probability)) as u64
+    }
+
+    // sends one instruction paid by the payer and adds its cost to the report.
+    // `rent_payer` pays the rent of the accounts created by the instruction.
+    async fn send(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&Keypair],
+        rent_payer: Option<Pubkey>,
+    ) -> Result<bool> {
+        let balance_before = match rent_payer {
+            Some(key) => self.env.context.banks_client.get_balance(key).await?,
+            None => 0,
+        };
+
+        self.report.transactions += 1;
+
+        match self.env.process_metered(&[instruction], signers).await {
+            Ok(execution) => {
+                self.report.compute_units += execution.compute_units;
+                self.report.fee_lamports += execution.fee;
+
+                if let Some(key) = rent_payer {
+                    let balance_after = self.env.context.banks_client.get_balance(key).await?;
+                    self.report.rent_lamports += balance_before.saturating_sub(balance_after);
+                }
+
+                Ok(true)
+            }
+            Err(err) => {
+                debug!("transaction failed: {}", err);
+                self.report.failed_transactions += 1;
+
+                Ok(false)
+            }
+        }
+    }
+}
+
+// position of a random item among those accepted by `filter`
+fn pick<T>(rng: &mut StdRng, items: &[T], filter: impl Fn(&T) -> bool) -> Option<usize> {
+    let candidates: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| filter(item))
+        .map(|(position, _)| position)
+        .collect();
+
+    candidates.choose(rng).copied()
+}
@@ -0,0 +1,111 @@
+use neobots::User;
+use neobots_sim::{config::UsageConfig, RoundReport, Simulation, SimulationConfig};
+use solana_sdk::signer::Signer;
+
+// a few bots posting and commenting, few enough that the rewards of a round
+// stay within its budget even at the max distribution rate
+fn config() -> SimulationConfig {
+    SimulationConfig {
+        rounds: 4,
+        seed: 7,
+        initial_bots: 3,
+        max_bots: 3,
+        claim_probability: 1.0,
+        usage: UsageConfig {
+            post: 0.5,
+            comment: 0.1,
+            upvote: 0.0,
+            downvote: 0.0,
+            like: 0.0,
+            banvote: 0.0,
+        },
+        ..SimulationConfig::default()
+    }
+}
+
+async fn run(config: SimulationConfig) -> (Simulation, Vec<RoundReport>) {
+    let rounds = config.rounds;
+    let mut simulation = Simulation::start(config).await;
+    let mut reports = Vec::new();
+
+    for _ in 0..rounds {
+        reports.push(simulation.run_round().await.unwrap());
+    }
+
+    (simulation, reports)
+}
+
+#[tokio::test]
+async fn rounds_keep_the_invariants() {
+    let (mut simulation, reports) = run(config()).await;
+
+    for (round, report) in reports.iter().enumerate() {
+        assert_eq!(report.round, round as u64);
+        assert!(report.rewards_earned <= report.round_max_distribution);
+        assert!(report.round_distributed <= report.round_max_distribution);
+    }
+
+    // everything minted sits in the token accounts of the bots, and of the
+    // payer for the crank rewards
+    let owners: Vec<_> = simulation
+        .bots()
+        .iter()
+        .map(|bot| bot.owner.pubkey())
+        .collect();
+    let payer = simulation.env().payer().pubkey();
+
+    let mut balances = simulation.env().token_balance(&payer).await;
+    for owner in &owners {
+        balances += simulation.env().token_balance(owner).await;
+    }
+
+    let supply = simulation.env().mint_supply().await;
+    assert_eq!(supply, reports.last().unwrap().supply);
+    assert_eq!(balances, supply);
+}
+
+#[tokio::test]
+async fn comments_go_to_the_posts_of_other_bots() {
+    let (mut simulation, _) = run(config()).await;
+
+    let comments = simulation.comments().to_vec();
+    assert!(!comments.is_empty());
+
+    let mut sent = vec![0; simulation.bots().len()];
+    let mut received = vec![0; simulation.bots().len()];
+    for comment in &comments {
+        assert_ne!(comment.author, comment.post_author);
+        sent[comment.author] += 1;
+        received[comment.post_author] += 1;
+    }
+
+    let users: Vec<_> = simulation.bots().iter().map(|bot| bot.user).collect();
+    for (index, key) in users.iter().enumerate() {
+        let user: User = simulation.env().account(key).await;
+        assert_eq!(user.comment_count, sent[index]);
+        assert_eq!(user.received_comment_count, received[index]);
+    }
+}
+
+#[tokio::test]
+async fn same_seed_same_rounds() {
+    let (_, first) = run(config()).await;
+    let (_, second) = run(config()).await;
+
+    // the costs depend on the random keys of the bots, not the activity
+    let activity = |reports: &[RoundReport]| -> Vec<_> {
+        reports
+            .iter()
+            .map(|report| {
+                (
+                    report.active_bots,
+                    report.transactions,
+                    report.failed_transactions,
+                    report.supply,
+                    report.rewards_earned,
+                )
+            })
+            .collect()
+    };
+    assert_eq!(activity(&first), activity(&second));
+}
@@ -0,0 +1,521 @@
+//! In-process test environment for the neobots program.
+//!
+//! The program runs natively inside `solana-program-test`. mpl-core and token
+//! metadata are the same BPF builds the localnet validator loads
+//! (`tests/ext_program`), so the tests run offline with plain `cargo test`.
+//!
+//! With `SBF_OUT_DIR` pointing to the output of `anchor build`
+//! (`target/deploy`), the SBF build of the program runs instead, and compute
+//! units are metered like on a validator.
+
+use std::path::Path;
+
+use anchor_lang::{
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction,
+        pubkey::Pubkey,
+    },
+    AccountDeserialize, AccountSerialize,
+};
+use anchor_spl::{
+    associated_token::spl_associated_token_account::instruction::create_associated_token_account,
+    token,
+    token_interface::{Mint, TokenAccount},
+};
+use mpl_core::instructions::{CreateCollectionV1Builder, CreateV1Builder, TransferV1Builder};
+use neobots::{Forum, RoundConfig, RoundStatus, User};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader,
+    clock::Clock,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::InstructionError,
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Lamports given to every wallet created by [`TestEnv::new_wallet`].
+pub const WALLET_LAMPORTS: u64 = 10_000_000_000;
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // anchor ties the accounts slice to the lifetime of the accounts, which
+    // `processor!` cannot express. leaking a copy is fine in tests.
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+
+    neobots::entry(program_id, accounts, data)
+}
+
+fn add_fixture(program_test: &mut ProgramTest, program_id: Pubkey, file: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/ext_program")
+        .join(file);
+    let data = std::fs::read(&path)
+        .unwrap_or_else(|err| panic!("cannot read fixture {}: {}", path.display(), err));
+
+    program_test.add_account(
+        program_id,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: bpf_loader::id(),
+            executable: true,
+            rent_epoch: 0,
+        },
+    );
+}
+
+pub fn program_test() -> ProgramTest {
+    let mut program_test =
+        ProgramTest::new("neobots", neobots::ID, processor!(process_instruction));
+    program_test.prefer_bpf(std::env::var_os("SBF_OUT_DIR").is_some());
+
+    add_fixture(&mut program_test, mpl_core::ID, "core.so");
+    add_fixture(&mut program_test, anchor_spl::metadata::ID, "metaplex.so");
+
+    program_test
+}
+
+/// A bot NFT of the forum collection with its user account.
+pub struct Bot {
+    pub owner: Keypair,
+    pub nft_mint: Pubkey,
+    pub user: Pubkey,
+}
+
+/// What a transaction sent by [`TestEnv::process_metered`] cost.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Execution {
+    /// Compute units consumed, only the CPIs to BPF programs are metered
+    /// when the program runs natively.
+    pub compute_units: u64,
+    /// Fee in lamports charged to the payer.
+    pub fee: u64,
+}
+
+pub struct TestEnv {
+    pub context: ProgramTestContext,
+    /// Collection of the bot NFTs, its update authority is the payer.
+    pub collection: Pubkey,
+    /// Program of the reward mint once the forum is initialized.
+    pub token_program: Pubkey,
+    nonce: u32,
+}
+
+impl TestEnv {
+    /// Environment without a forum, with the bot collection created.
+    pub async fn start() -> Self {
+        let context = program_test().start_with_context().await;
+
+        let mut env = Self {
+            context,
+            collection: Pubkey::default(),
+            token_program: token::ID,
+            nonce: 0,
+        };
+        env.collection = env.create_collection().await;
+
+        env
+    }
+
+    /// Forum with a legacy SPL reward mint, administered by the payer.
+    pub async fn new() -> Self {
+        Self::with_round_params(None, None).await
+    }
+
+    /// Same as [`TestEnv::new`], the forum starts with `round_config` and
+    /// `round_status` instead of the initial values of the program.
+    pub async fn with_round_params(
+        round_config: Option<RoundConfig>,
+        round_status: Option<RoundStatus>,
+    ) -> Self {
+        let mut env = Self::start().await;
+
+        let instruction = instructions::initialize_forum(
+            FORUM_NAME,
+            env.payer().pubkey(),
+            env.collection,
+            token::ID,
+            round_config,
+            round_status,
+        );
+        env.process_ok(&[instruction], &[]).await;
+
+        env
+    }
+
+    /// Forum with a Token-2022 reward mint, administered by the payer.
+    pub async fn new_token_2022(transfer_fee_basis_points: u16, maximum_fee: u64) -> Self {
+        let mut env = Self::start().await;
+
+        let instruction = instructions::initialize_forum_token_2022(
+            FORUM_NAME,
+            env.payer().pubkey(),
+            env.collection,
+            transfer_fee_basis_points,
+            maximum_fee,
+            None,
+            None,
+        );
+        env.process_ok(&[instruction], &[]).await;
+        env.token_program = anchor_spl::token_2022::ID;
+
+        env
+    }
+
+    pub fn payer(&self) -> Keypair {
+        self.context.payer.insecure_clone()
+    }
+
+    pub fn forum(&self) -> Pubkey {
+        pda::forum(FORUM_NAME)
+    }
+
+    /// Sends the instructions paid by the payer, `signers` are the other signers.
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let transaction = self.transaction(instructions, signers).await?;
+
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    /// Same as [`TestEnv::process`], also returning what the transaction cost.
+    pub async fn process_metered(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Execution, BanksClientError> {
+        let transaction = self.transaction(instructions, signers).await?;
+        let fee = self
+            .context
+            .banks_client
+            .get_fee_for_message(transaction.message.clone())
+            .await?
+            .unwrap_or_default();
+
+        let processed = self
+            .context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+        processed.result?;
+
+        Ok(Execution {
+            compute_units: processed
+                .metadata
+                .map_or(0, |metadata| metadata.compute_units_consumed),
+            fee,
+        })
+    }
+
+    async fn transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Transaction, BanksClientError> {
+        // a distinct compute unit limit keeps identical transactions of the
+        // same blockhash from being deduplicated
+        self.nonce += 1;
+        let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+            1_400_000 - self.nonce,
+        )];
+        all_instructions.extend_from_slice(instructions);
+
+        let payer = self.payer();
+        let mut all_signers = vec![&payer];
+        all_signers.extend_from_slice(signers);
+
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+
+        Ok(Transaction::new_signed_with_payer(
+            &all_instructions,
+            Some(&payer.pubkey()),
+            &all_signers,
+            blockhash,
+        ))
+    }
+
+    pub async fn process_ok(&mut self, instructions: &[Instruction], signers: &[&Keypair]) {
+        if let Err(err) = self.process(instructions, signers).await {
+            panic!("transaction failed: {:?}", err);
+        }
+    }
+
+    pub async fn new_wallet(&mut self) -> Keypair {
+        let wallet = Keypair::new();
+        let instruction =
+            system_instruction::transfer(&self.payer().pubkey(), &wallet.pubkey(), WALLET_LAMPORTS);
+        self.process_ok(&[instruction], &[]).await;
+
+        wallet
+    }
+
+    pub async fn create_collection(&mut self) -> Pubkey {
+        let collection = Keypair::new();
+        let instruction = CreateCollectionV1Builder::new()
+            .collection(collection.pubkey())
+            .payer(self.payer().pubkey())
+            .name("neobots".to_string())
+            .uri(String::new())
+            .instruction();
+        self.process_ok(&[instruction], &[&collection]).await;
+
+        collection.pubkey()
+    }
+
+    /// Mints an mpl-core asset of `collection` to `owner`.
+    pub async fn create_asset(&mut self, collection: &Pubkey, owner: &Pubkey) -> Pubkey {
+        let asset = Keypair::new();
+        let instruction = CreateV1Builder::new()
+            .asset(asset.pubkey())
+            .collection(Some(*collection))
+            .payer(self.payer().pubkey())
+            .owner(Some(*owner))
+            .name("bot".to_string())
+            .uri(String::new())
+            .instruction();
+        self.process_ok(&[instruction], &[&asset]).await;
+
+        asset.pubkey()
+    }
+
+    pub async fn transfer_asset(&mut self, asset: &Pubkey, owner: &Keypair, new_owner: &Pubkey) {
+        let instruction = TransferV1Builder::new()
+            .asset(*asset)
+            .collection(Some(self.collection))
+            .payer(self.payer().pubkey())
+            .authority(Some(owner.pubkey()))
+            .new_owner(*new_owner)
+            .instruction();
+        self.process_ok(&[instruction], &[owner]).await;
+    }
+
+    /// New wallet holding a bot NFT of the forum collection, with its user initialized.
+    pub async fn create_bot(&mut self, name: &str) -> Bot {
+        let owner = self.new_wallet().await;
+        let collection = self.collection;
+        let nft_mint = self.create_asset(&collection, &owner.pubkey()).await;
+
+        let instruction = instructions::initialize_user(
+            FORUM_NAME,
+            &nft_mint,
+            owner.pubkey(),
+            "curious".to_string(),
+            name.to_string(),
+            "thumb".to_string(),
+        );
+        self.process_ok(&[instruction], &[&owner]).await;
+
+        Bot {
+            owner,
+            nft_mint,
+            user: pda::user(&nft_mint),
+        }
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, key: &Pubkey) -> T {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*key)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {} does not exist", key));
+
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// Overwrites the data of a program account, to reach states that no
+    /// instruction sequence gets to in a test (e.g. counters at their max).
+    pub async fn set_account<T: AccountSerialize>(&mut self, key: &Pubkey, value: &T) {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*key)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {} does not exist", key));
+
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+
+        self.context.set_account(key, &account.into());
+    }
+
+    /// Replaces the data of a program account, resized to `data` and funded
+    /// with the rent of that size, e.g. to restore the layout of an older
+    /// program version.
+    pub async fn set_account_data(&mut self, key: &Pubkey, data: Vec<u8>) {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*key)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {} does not exist", key));
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+
+        account.lamports = rent.minimum_balance(data.len());
+        account.data = data;
+
+        self.context.set_account(key, &account.into());
+    }
+
+    pub async fn exists(&mut self, key: &Pubkey) -> bool {
+        self.context
+            .banks_client
+            .get_account(*key)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    pub async fn forum_account(&mut self) -> Forum {
+        let forum = self.forum();
+        self.account(&forum).await
+    }
+
+    pub async fn user_account(&mut self, bot: &Bot) -> User {
+        self.account(&bot.user).await
+    }
+
+    pub async fn mint_supply(&mut self) -> u64 {
+        self.account::<Mint>(&pda::mint()).await.supply
+    }
+
+    /// Balance of the reward token ATA of `owner`, 0 if it does not exist.
+    pub async fn token_balance(&mut self, owner: &Pubkey) -> u64 {
+        let token_account = pda::associated_token(owner, &pda::mint(), &self.token_program);
+
+        if !self.exists(&token_account).await {
+            return 0;
+        }
+
+        self.account::<TokenAccount>(&token_account).await.amount
+    }
+
+    /// Creates the reward token ATA of `owner`.
+    pub async fn create_token_account(&mut self, owner: &Pubkey) {
+        let instruction = create_associated_token_account(
+            &self.payer().pubkey(),
+            owner,
+            &pda::mint(),
+            &self.token_program,
+        );
+        self.process_ok(&[instruction], &[]).await;
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context.banks_client.get_sysvar().await.unwrap()
+    }
+
+    /// Moves to the next slot and `seconds` later.
+    pub async fn warp(&mut self, seconds: i64) {
+        let clock = self.clock().await;
+        self.context.warp_to_slot(clock.slot + 1).unwrap();
+
+        // the new slot recomputes the clock, keep moving forward from where it was
+        let mut next = self.clock().await;
+        next.unix_timestamp = clock.unix_timestamp + seconds;
+        self.context.set_sysvar(&next);
+    }
+
+    /// Moves the clock `seconds` later without leaving the current slot.
+    pub async fn warp_in_slot(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+
+    /// Next post of `bot`, tagged `general`. Returns the post account.
+    pub async fn create_post(&mut self, bot: &Bot) -> Result<Pubkey, BanksClientError> {
+        let sequence = self.user_account(bot).await.post_count;
+        let instruction = instructions::create_post(
+            FORUM_NAME,
+            &bot.nft_mint,
+            bot.owner.pubkey(),
+            sequence,
+            format!("post {}", sequence),
+            "general".to_string(),
+            [0; 32],
+            String::new(),
+        );
+        self.process(&[instruction], &[&bot.owner]).await?;
+
+        Ok(pda::post(&self.forum(), &bot.user, sequence))
+    }
+
+    /// Comment of `sender` on post `post_sequence` of `post_author`.
+    pub async fn add_comment(
+        &mut self,
+        sender: &Bot,
+        post_author: &Bot,
+        post_sequence: u32,
+    ) -> Result<(), BanksClientError> {
+        let instruction = instructions::add_comment(
+            FORUM_NAME,
+            &post_author.user,
+            post_sequence,
+            &sender.nft_mint,
+            sender.owner.pubkey(),
+            format!("comment {}", self.nonce),
+            [0; 32],
+            String::new(),
+        );
+
+        self.process(&[instruction], &[&sender.owner]).await
+    }
+
+    /// Claims the rewards of `bot` to the ATA of its owner.
+    pub async fn claim(&mut self, bot: &Bot) -> Result<(), BanksClientError> {
+        let vested = self.forum_account().await.round_config.vesting_duration > 0;
+        let instruction = instructions::claim(
+            FORUM_NAME,
+            &bot.nft_mint,
+            bot.owner.pubkey(),
+            self.token_program,
+            vested,
+        );
+
+        self.process(&[instruction], &[&bot.owner]).await
+    }
+
+    /// Lets the current round end and advances it, signed by the payer.
+    pub async fn advance_round(&mut self) {
+        let round_duration = self.forum_account().await.round_status.round_duration;
+        self.warp(round_duration as i64).await;
+
+        let instruction =
+            instructions::advance_round(FORUM_NAME, self.payer().pubkey(), self.token_program);
+        self.process_ok(&[instruction], &[]).await;
+    }
+}
+
+/// Asserts that the transaction failed with the custom program error `expected`,
+/// e.g. `NeobotsError::NFTNotOwned` or an anchor `ErrorCode`.
+pub fn assert_error(result: Result<(), BanksClientError>, expected: impl Into<u32>) {
+    let expected = expected.into();
+
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) => assert_eq!(code, expected, "unexpected error code"),
+        other => panic!("expected custom error {}, got {:?}", expected, other),
+    }
+}
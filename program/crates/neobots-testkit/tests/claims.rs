@@ -0,0 +1,171 @@
+use neobots::{NeobotsError, Vesting, INITIAL_ROUND_CONFIG, INITIAL_SUPPLY_CONFIG};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use neobots_testkit::{assert_error, Bot, TestEnv};
+use solana_sdk::signer::Signer;
+
+// alice posts, bob comments: bob earns k_comment
+async fn earn(env: &mut TestEnv) -> (Bot, Bot) {
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+
+    (alice, bob)
+}
+
+#[tokio::test]
+async fn claim_mints_to_the_holder() {
+    let mut env = TestEnv::new().await;
+    let (_, bob) = earn(&mut env).await;
+
+    env.claim(&bob).await.unwrap();
+
+    assert_eq!(
+        env.token_balance(&bob.owner.pubkey()).await,
+        INITIAL_ROUND_CONFIG.k_comment
+    );
+    assert_eq!(env.user_account(&bob).await.claimable_amount, 0);
+    assert_eq!(
+        env.forum_account().await.round_distributed,
+        INITIAL_ROUND_CONFIG.k_comment
+    );
+
+    assert_error(
+        env.claim(&bob).await,
+        NeobotsError::NotEnoughClaimableAmount,
+    );
+}
+
+#[tokio::test]
+async fn claim_with_token_2022() {
+    let mut env = TestEnv::new_token_2022(0, 0).await;
+    let (_, bob) = earn(&mut env).await;
+
+    env.claim(&bob).await.unwrap();
+
+    assert_eq!(
+        env.token_balance(&bob.owner.pubkey()).await,
+        INITIAL_ROUND_CONFIG.k_comment
+    );
+}
+
+#[tokio::test]
+async fn claim_requires_nft_owner() {
+    let mut env = TestEnv::new().await;
+    let (_, bob) = earn(&mut env).await;
+    let stranger = env.new_wallet().await;
+
+    let instruction = instructions::claim(
+        FORUM_NAME,
+        &bob.nft_mint,
+        stranger.pubkey(),
+        env.token_program,
+        false,
+    );
+
+    assert_error(
+        env.process(&[instruction], &[&stranger]).await,
+        NeobotsError::NFTNotOwned,
+    );
+}
+
+#[tokio::test]
+async fn claim_is_bounded_by_max_supply() {
+    let mut env = TestEnv::new().await;
+    let (_, bob) = earn(&mut env).await;
+
+    let instruction = instructions::update_supply_config(
+        FORUM_NAME,
+        env.payer().pubkey(),
+        0,
+        INITIAL_SUPPLY_CONFIG.emission_schedule,
+    );
+    env.process_ok(&[instruction], &[]).await;
+
+    assert_error(env.claim(&bob).await, NeobotsError::MaxSupplyReached);
+}
+
+#[tokio::test]
+async fn vested_claim_is_released_over_time() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer().pubkey();
+
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.vesting_duration = 1_000;
+    let instruction = instructions::update_round_config(FORUM_NAME, payer, round_config);
+    env.process_ok(&[instruction], &[]).await;
+    env.advance_round().await;
+
+    let (_, bob) = earn(&mut env).await;
+    let owner = bob.owner.pubkey();
+    let amount = INITIAL_ROUND_CONFIG.k_comment;
+
+    env.claim(&bob).await.unwrap();
+
+    assert_eq!(env.token_balance(&owner).await, 0);
+    assert_eq!(
+        env.token_balance(&pda::vesting(&bob.nft_mint)).await,
+        amount
+    );
+
+    let vesting: Vesting = env.account(&pda::vesting(&bob.nft_mint)).await;
+    assert_eq!(vesting.total_amount, amount);
+    assert_eq!(vesting.duration, 1_000);
+
+    let release = instructions::release_vested(FORUM_NAME, &bob.nft_mint, owner, env.token_program);
+
+    env.warp(500).await;
+    env.process_ok(&[release.clone()], &[&bob.owner]).await;
+
+    let released = env.token_balance(&owner).await;
+    assert!(released > 0 && released < amount);
+
+    env.warp(1_000).await;
+    env.process_ok(&[release.clone()], &[&bob.owner]).await;
+    assert_eq!(env.token_balance(&owner).await, amount);
+
+    assert_error(
+        env.process(&[release], &[&bob.owner]).await,
+        NeobotsError::NothingToRelease,
+    );
+}
+
+#[tokio::test]
+async fn vested_claim_never_unlocks_early() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer().pubkey();
+
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.vesting_duration = 1_000;
+    let instruction = instructions::update_round_config(FORUM_NAME, payer, round_config);
+    env.process_ok(&[instruction], &[]).await;
+    env.advance_round().await;
+
+    let (alice, bob) = earn(&mut env).await;
+    let owner = bob.owner.pubkey();
+    let amount = INITIAL_ROUND_CONFIG.k_comment;
+    env.claim(&bob).await.unwrap();
+
+    let vesting: Vesting = env.account(&pda::vesting(&bob.nft_mint)).await;
+    let end_time = vesting.start_time + vesting.duration as i64;
+
+    // a second claim just before the first schedule ends
+    env.warp(990).await;
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    env.claim(&bob).await.unwrap();
+    let now = env.clock().await.unix_timestamp;
+
+    let vesting: Vesting = env.account(&pda::vesting(&bob.nft_mint)).await;
+    assert_eq!(vesting.start_time, now);
+    assert_eq!(vesting.duration, 1_000);
+    assert!(vesting.start_time + vesting.duration as i64 > end_time);
+
+    // past the end of the first schedule, the new claim is still mostly locked
+    let release = instructions::release_vested(FORUM_NAME, &bob.nft_mint, owner, env.token_program);
+    env.warp(20).await;
+    env.process_ok(&[release], &[&bob.owner]).await;
+
+    let released = env.token_balance(&owner).await;
+    assert!(released < amount + amount / 10);
+}
@@ -0,0 +1,213 @@
+use neobots::{NeobotsError, Post, ReactionType, INITIAL_ACTION_POINTS, INITIAL_ROUND_CONFIG};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use neobots_testkit::{assert_error, TestEnv};
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn create_post() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+
+    let post_key = env.create_post(&bot).await.unwrap();
+    let post: Post = env.account(&post_key).await;
+
+    assert_eq!(post.author, bot.user);
+    assert_eq!(post.sequence, 0);
+    assert_eq!(post.content, "post 0");
+    assert!(env.exists(&pda::tag(&env.forum(), "general")).await);
+
+    let user = env.user_account(&bot).await;
+    assert_eq!(user.post_count, 1);
+    assert_eq!(user.action_points.post, INITIAL_ACTION_POINTS.post - 1);
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_activity.post_count, 1);
+    assert_eq!(forum.round_activity.active_users, 1);
+}
+
+#[tokio::test]
+async fn create_post_requires_action_points() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+
+    for _ in 0..INITIAL_ACTION_POINTS.post {
+        env.create_post(&bot).await.unwrap();
+    }
+
+    assert_error(
+        env.create_post(&bot).await.map(|_| ()),
+        NeobotsError::NotEnoughActionPoints,
+    );
+}
+
+#[tokio::test]
+async fn create_post_rejects_long_content_uri() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+
+    let instruction = instructions::create_post(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        0,
+        "hello".to_string(),
+        "general".to_string(),
+        [0; 32],
+        "u".repeat(65),
+    );
+
+    assert_error(
+        env.process(&[instruction], &[&bot.owner]).await,
+        NeobotsError::InvalidContentUri,
+    );
+}
+
+#[tokio::test]
+async fn create_post_rejects_stranger() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+    let stranger = env.new_wallet().await;
+
+    let instruction = instructions::create_post(
+        FORUM_NAME,
+        &bot.nft_mint,
+        stranger.pubkey(),
+        0,
+        "hello".to_string(),
+        "general".to_string(),
+        [0; 32],
+        String::new(),
+    );
+
+    assert_error(
+        env.process(&[instruction], &[&stranger]).await,
+        NeobotsError::NFTNotOwned,
+    );
+}
+
+#[tokio::test]
+async fn add_comment_rewards_commenter_and_post_author() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+
+    // distribution rate is 1x
+    let bob_user = env.user_account(&bob).await;
+    assert_eq!(bob_user.comment_count, 1);
+    assert_eq!(bob_user.claimable_amount, INITIAL_ROUND_CONFIG.k_comment);
+
+    let alice_user = env.user_account(&alice).await;
+    assert_eq!(
+        alice_user.claimable_amount,
+        INITIAL_ROUND_CONFIG.k_comment_receiver
+    );
+
+    assert_eq!(env.forum_account().await.round_activity.comment_count, 1);
+}
+
+#[tokio::test]
+async fn add_comment_requires_action_points() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+
+    env.create_post(&alice).await.unwrap();
+
+    for _ in 0..INITIAL_ACTION_POINTS.comment {
+        env.add_comment(&bob, &alice, 0).await.unwrap();
+    }
+
+    assert_error(
+        env.add_comment(&bob, &alice, 0).await,
+        NeobotsError::NotEnoughActionPoints,
+    );
+}
+
+#[tokio::test]
+async fn add_reaction() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    let carol = env.create_bot("carol").await;
+
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    let bob_claimable = env.user_account(&bob).await.claimable_amount;
+
+    let react = |reaction_type| {
+        instructions::add_reaction(
+            FORUM_NAME,
+            &alice.user,
+            0,
+            &bob.user,
+            1,
+            &carol.nft_mint,
+            carol.owner.pubkey(),
+            reaction_type,
+        )
+    };
+
+    env.process_ok(&[react(ReactionType::Upvote)], &[&carol.owner])
+        .await;
+    env.process_ok(&[react(ReactionType::Like)], &[&carol.owner])
+        .await;
+
+    let carol_user = env.user_account(&carol).await;
+    assert_eq!(carol_user.upvote_count, 1);
+    assert_eq!(carol_user.like_count, 1);
+    assert_eq!(carol_user.reaction_count, 2);
+    assert_eq!(
+        carol_user.action_points.upvote,
+        INITIAL_ACTION_POINTS.upvote - 1
+    );
+    assert_eq!(
+        carol_user.claimable_amount,
+        2 * INITIAL_ROUND_CONFIG.k_reaction_giver
+    );
+
+    let bob_user = env.user_account(&bob).await;
+    assert_eq!(bob_user.received_upvote_count, 1);
+    assert_eq!(bob_user.received_like_count, 1);
+    assert_eq!(
+        bob_user.claimable_amount,
+        bob_claimable + 2 * INITIAL_ROUND_CONFIG.k_reaction_receiver
+    );
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_activity.upvote_count, 1);
+    assert_eq!(forum.round_activity.like_count, 1);
+}
+
+#[tokio::test]
+async fn add_reaction_requires_action_points() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    let carol = env.create_bot("carol").await;
+
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+
+    for sequence in 0..=INITIAL_ACTION_POINTS.banvote {
+        let instruction = instructions::add_reaction(
+            FORUM_NAME,
+            &alice.user,
+            0,
+            &bob.user,
+            sequence as u32,
+            &carol.nft_mint,
+            carol.owner.pubkey(),
+            ReactionType::Banvote,
+        );
+        let result = env.process(&[instruction], &[&carol.owner]).await;
+
+        if sequence < INITIAL_ACTION_POINTS.banvote {
+            result.unwrap();
+        } else {
+            assert_error(result, NeobotsError::NotEnoughActionPoints);
+        }
+    }
+}
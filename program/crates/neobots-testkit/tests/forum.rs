@@ -0,0 +1,335 @@
+use anchor_lang::error::ErrorCode;
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token_2022};
+use neobots::{
+    devnet, is_test_round_config, mainnet, EmissionSchedule, NeobotsError, RoundStatus,
+    UserCounter, INITIAL_ROUND_CONFIG, INITIAL_ROUND_STATUS, INITIAL_SUPPLY_CONFIG,
+};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use neobots_testkit::{assert_error, TestEnv};
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn initialize_forum() {
+    let mut env = TestEnv::new().await;
+    let forum = env.forum_account().await;
+
+    assert_eq!(forum.admin, env.payer().pubkey());
+    assert_eq!(forum.mint, pda::mint());
+    assert_eq!(forum.nft_collection, env.collection);
+    assert_eq!(forum.round_status.round_number, 0);
+    assert_eq!(
+        forum.round_status.round_duration,
+        INITIAL_ROUND_CONFIG.round_duration
+    );
+    assert_eq!(
+        forum.supply_config.max_supply,
+        INITIAL_SUPPLY_CONFIG.max_supply
+    );
+
+    let user_counter: UserCounter = env.account(&pda::user_counter()).await;
+    assert_eq!(user_counter.count, 0);
+
+    assert!(env.exists(&pda::token_metadata(&pda::mint())).await);
+}
+
+#[tokio::test]
+async fn initialize_forum_rejects_other_names() {
+    let mut env = TestEnv::start().await;
+
+    let instruction = instructions::initialize_forum(
+        "other_forum",
+        env.payer().pubkey(),
+        env.collection,
+        anchor_spl::token::ID,
+        None,
+        None,
+    );
+
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        NeobotsError::InvalidForumName,
+    );
+}
+
+#[tokio::test]
+async fn initialize_forum_with_round_config() {
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.round_duration = 600;
+    round_config.default_action_points.post = 5;
+
+    let mut env = TestEnv::with_round_params(Some(round_config), None).await;
+    let forum = env.forum_account().await;
+
+    assert_eq!(forum.round_config.round_duration, 600);
+    assert_eq!(forum.next_round_config.round_duration, 600);
+    assert_eq!(forum.round_config.default_action_points.post, 5);
+    // the first round follows the round config
+    assert_eq!(forum.round_status.round_duration, 600);
+    assert_eq!(
+        forum.round_status.round_max_distribution,
+        INITIAL_ROUND_STATUS.round_max_distribution
+    );
+}
+
+#[tokio::test]
+async fn initialize_forum_with_round_status() {
+    let round_status = RoundStatus {
+        round_max_distribution: INITIAL_ROUND_STATUS.round_max_distribution * 2,
+        round_duration: 60,
+        ..INITIAL_ROUND_STATUS
+    };
+
+    let mut env = TestEnv::with_round_params(None, Some(round_status)).await;
+    let forum = env.forum_account().await;
+
+    assert_eq!(forum.round_status.round_duration, 60);
+    assert_eq!(
+        forum.round_config.round_duration,
+        INITIAL_ROUND_CONFIG.round_duration
+    );
+    // emission schedules start from the first round budget
+    assert_eq!(
+        forum.supply_config.initial_round_emission,
+        INITIAL_ROUND_STATUS.round_max_distribution * 2
+    );
+}
+
+#[tokio::test]
+async fn initialize_forum_rejects_invalid_round_params() {
+    let mut invalid_config = INITIAL_ROUND_CONFIG;
+    invalid_config.round_duration = 0;
+
+    let invalid_status = RoundStatus {
+        round_distribution_rate: INITIAL_ROUND_CONFIG.round_max_distribution_rate + 1,
+        ..INITIAL_ROUND_STATUS
+    };
+
+    for (round_config, round_status) in [(Some(invalid_config), None), (None, Some(invalid_status))]
+    {
+        let mut env = TestEnv::start().await;
+
+        let instruction = instructions::initialize_forum(
+            FORUM_NAME,
+            env.payer().pubkey(),
+            env.collection,
+            anchor_spl::token::ID,
+            round_config,
+            round_status,
+        );
+
+        assert_error(
+            env.process(&[instruction], &[]).await,
+            NeobotsError::InvalidRoundConfig,
+        );
+    }
+}
+
+#[tokio::test]
+async fn initialize_forum_token_2022() {
+    let mut env = TestEnv::new_token_2022(100, 1_000_000).await;
+    let forum = env.forum_account().await;
+
+    assert_eq!(forum.mint, pda::mint());
+
+    let mint = env
+        .context
+        .banks_client
+        .get_account(pda::mint())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(mint.owner, token_2022::ID);
+}
+
+#[tokio::test]
+async fn update_round_config_takes_effect_next_round() {
+    let mut env = TestEnv::new().await;
+    let admin = env.payer().pubkey();
+
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.round_duration = 600;
+
+    let instruction = instructions::update_round_config(FORUM_NAME, admin, round_config);
+    env.process_ok(&[instruction], &[]).await;
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_config.round_duration, 300);
+    assert_eq!(forum.next_round_config.round_duration, 600);
+
+    env.advance_round().await;
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_config.round_duration, 600);
+    assert_eq!(forum.round_status.round_duration, 600);
+}
+
+#[tokio::test]
+async fn update_round_config_rejects_invalid_config() {
+    let mut env = TestEnv::new().await;
+
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.round_duration = 0;
+
+    let instruction =
+        instructions::update_round_config(FORUM_NAME, env.payer().pubkey(), round_config);
+
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        NeobotsError::InvalidRoundConfig,
+    );
+}
+
+#[tokio::test]
+async fn update_round_config_accepts_every_profile() {
+    let mut env = TestEnv::new().await;
+    let admin = env.payer().pubkey();
+
+    for round_config in [devnet::INITIAL_ROUND_CONFIG, mainnet::INITIAL_ROUND_CONFIG] {
+        let instruction = instructions::update_round_config(FORUM_NAME, admin, round_config);
+        env.process_ok(&[instruction], &[]).await;
+    }
+
+    assert!(is_test_round_config(&INITIAL_ROUND_CONFIG));
+    assert!(is_test_round_config(&devnet::INITIAL_ROUND_CONFIG));
+    assert!(!is_test_round_config(&mainnet::INITIAL_ROUND_CONFIG));
+}
+
+#[tokio::test]
+async fn update_round_config_requires_admin() {
+    let mut env = TestEnv::new().await;
+    let stranger = env.new_wallet().await;
+
+    let instruction =
+        instructions::update_round_config(FORUM_NAME, stranger.pubkey(), INITIAL_ROUND_CONFIG);
+
+    assert_error(
+        env.process(&[instruction], &[&stranger]).await,
+        NeobotsError::AccessDenied,
+    );
+}
+
+#[tokio::test]
+async fn renounce_supply_increase_locks_max_supply() {
+    let mut env = TestEnv::new().await;
+    let admin = env.payer().pubkey();
+    let max_supply = INITIAL_SUPPLY_CONFIG.max_supply;
+
+    let instruction = instructions::update_supply_config(
+        FORUM_NAME,
+        admin,
+        max_supply / 2,
+        EmissionSchedule::Halving {
+            interval_rounds: 10,
+        },
+    );
+    env.process_ok(&[instruction], &[]).await;
+
+    let instruction = instructions::renounce_supply_increase(FORUM_NAME, admin);
+    env.process_ok(&[instruction], &[]).await;
+
+    let forum = env.forum_account().await;
+    assert!(forum.supply_config.max_supply_locked);
+    assert_eq!(forum.supply_config.max_supply, max_supply / 2);
+
+    let instruction = instructions::update_supply_config(
+        FORUM_NAME,
+        admin,
+        max_supply,
+        EmissionSchedule::Constant,
+    );
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        NeobotsError::MaxSupplyLocked,
+    );
+
+    // lowering is still allowed
+    let instruction = instructions::update_supply_config(
+        FORUM_NAME,
+        admin,
+        max_supply / 4,
+        EmissionSchedule::Constant,
+    );
+    env.process_ok(&[instruction], &[]).await;
+}
+
+#[tokio::test]
+async fn update_supply_config_rejects_invalid_schedule() {
+    let mut env = TestEnv::new().await;
+
+    let instruction = instructions::update_supply_config(
+        FORUM_NAME,
+        env.payer().pubkey(),
+        INITIAL_SUPPLY_CONFIG.max_supply,
+        EmissionSchedule::Halving { interval_rounds: 0 },
+    );
+
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        NeobotsError::InvalidSupplyConfig,
+    );
+}
+
+#[tokio::test]
+async fn update_token_metadata() {
+    let mut env = TestEnv::new().await;
+    let stranger = env.new_wallet().await;
+
+    let update = |admin| {
+        instructions::update_token_metadata(
+            FORUM_NAME,
+            admin,
+            "Neobots".to_string(),
+            "NBT".to_string(),
+            "https://neobots.example/token.json".to_string(),
+            false,
+        )
+    };
+
+    env.process_ok(&[update(env.payer().pubkey())], &[]).await;
+
+    assert_error(
+        env.process(&[update(stranger.pubkey())], &[&stranger])
+            .await,
+        NeobotsError::AccessDenied,
+    );
+}
+
+#[tokio::test]
+async fn update_token_metadata_token_2022() {
+    let mut env = TestEnv::new_token_2022(0, 0).await;
+
+    let instruction = instructions::update_token_metadata_token_2022(
+        FORUM_NAME,
+        env.payer().pubkey(),
+        "Neobots".to_string(),
+        "NBT".to_string(),
+        "https://neobots.example/token.json".to_string(),
+        false,
+    );
+    env.process_ok(&[instruction], &[]).await;
+}
+
+#[tokio::test]
+async fn harvest_transfer_fees() {
+    let mut env = TestEnv::new_token_2022(100, 1_000_000).await;
+    let admin = env.payer().pubkey();
+    env.create_token_account(&admin).await;
+
+    let instruction = instructions::harvest_transfer_fees(FORUM_NAME, admin, &admin, &[]);
+    env.process_ok(&[instruction], &[]).await;
+
+    // the treasury must be the admin ATA
+    let stranger = env.new_wallet().await;
+    let mut instruction = instructions::harvest_transfer_fees(FORUM_NAME, admin, &admin, &[]);
+    instruction.accounts[2].pubkey = get_associated_token_address_with_program_id(
+        &stranger.pubkey(),
+        &pda::mint(),
+        &token_2022::ID,
+    );
+    env.create_token_account(&stranger.pubkey()).await;
+
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        ErrorCode::ConstraintTokenOwner,
+    );
+}
@@ -0,0 +1,463 @@
+//! Random instruction sequences against the program. After every transaction,
+//! failed or not, the accounting invariants the handlers rely on must hold.
+//!
+//! `PROPTEST_CASES=1000 cargo test -p neobots-testkit --test invariants` runs
+//! a longer campaign.
+
+use neobots::{
+    ActionPoints, EmissionSchedule, Forum, OperatorSession, ReactionType, User, TOKEN_UNIT,
+};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use neobots_economics::needs_action_point_reset;
+use neobots_testkit::{Bot, TestEnv};
+use proptest::{collection::vec, prelude::*};
+use solana_program_test::BanksClientError;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
+
+const BOTS: usize = 3;
+const PRICE_PER_COMMENT: u64 = TOKEN_UNIT / 100;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Post {
+        bot: usize,
+    },
+    // `post` and `comment` pick among the posts and comments created so far
+    Comment {
+        sender: usize,
+        post: usize,
+    },
+    OperatorComment {
+        sender: usize,
+        post: usize,
+    },
+    Reaction {
+        sender: usize,
+        comment: usize,
+        reaction_type: ReactionType,
+    },
+    ResetActionPoints {
+        bot: usize,
+    },
+    Claim {
+        bot: usize,
+    },
+    Deposit {
+        bot: usize,
+        amount: u64,
+    },
+    Withdraw {
+        bot: usize,
+        amount: u64,
+    },
+    AdvanceRound,
+}
+
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        4 => 0..4 * PRICE_PER_COMMENT,
+        1 => Just(u64::MAX),
+    ]
+}
+
+fn reaction_type() -> impl Strategy<Value = ReactionType> {
+    prop_oneof![
+        Just(ReactionType::Upvote),
+        Just(ReactionType::Downvote),
+        Just(ReactionType::Like),
+        Just(ReactionType::Banvote),
+    ]
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        3 => (0..BOTS).prop_map(|bot| Action::Post { bot }),
+        4 => (0..BOTS, any::<usize>()).prop_map(|(sender, post)| Action::Comment { sender, post }),
+        2 => (0..BOTS, any::<usize>())
+            .prop_map(|(sender, post)| Action::OperatorComment { sender, post }),
+        4 => (0..BOTS, any::<usize>(), reaction_type()).prop_map(
+            |(sender, comment, reaction_type)| Action::Reaction {
+                sender,
+                comment,
+                reaction_type,
+            }
+        ),
+        1 => (0..BOTS).prop_map(|bot| Action::ResetActionPoints { bot }),
+        3 => (0..BOTS).prop_map(|bot| Action::Claim { bot }),
+        2 => (0..BOTS, amount()).prop_map(|(bot, amount)| Action::Deposit { bot, amount }),
+        2 => (0..BOTS, amount()).prop_map(|(bot, amount)| Action::Withdraw { bot, amount }),
+        1 => Just(Action::AdvanceRound),
+    ]
+}
+
+struct Harness {
+    env: TestEnv,
+    bots: Vec<Bot>,
+    operator: Keypair,
+    // (author, sequence)
+    posts: Vec<(usize, u32)>,
+    // (post author, post sequence, author, sequence)
+    comments: Vec<(usize, u32, usize, u32)>,
+    minted_by_claims: u64,
+    minted_by_crank: u64,
+}
+
+impl Harness {
+    /// Every bot has a session with the same operator, and lets it comment.
+    async fn new(max_supply: Option<u64>) -> Self {
+        let mut env = TestEnv::new().await;
+        let payer = env.payer().pubkey();
+
+        if let Some(max_supply) = max_supply {
+            let instruction = instructions::update_supply_config(
+                FORUM_NAME,
+                payer,
+                max_supply,
+                EmissionSchedule::Constant,
+            );
+            env.process_ok(&[instruction], &[]).await;
+        }
+
+        let instruction = instructions::initialize_operator_pool(FORUM_NAME, payer);
+        env.process_ok(&[instruction], &[]).await;
+
+        let operator = env.new_wallet().await;
+        let instruction = instructions::initialize_operator(
+            FORUM_NAME,
+            operator.pubkey(),
+            "operator".to_string(),
+            PRICE_PER_COMMENT,
+            PRICE_PER_COMMENT,
+            PRICE_PER_COMMENT,
+            PRICE_PER_COMMENT,
+        );
+        env.process_ok(&[instruction], &[&operator]).await;
+
+        let mut bots = Vec::new();
+        for index in 0..BOTS {
+            let bot = env.create_bot(&format!("bot {}", index)).await;
+            let owner = bot.owner.pubkey();
+
+            let instructions = [
+                instructions::initialize_operator_session(
+                    FORUM_NAME,
+                    &bot.nft_mint,
+                    owner,
+                    operator.pubkey(),
+                    env.token_program,
+                ),
+                instructions::set_operator(FORUM_NAME, &bot.nft_mint, owner, operator.pubkey()),
+                instructions::set_user_operator(
+                    FORUM_NAME,
+                    &bot.nft_mint,
+                    owner,
+                    pda::operator(&operator.pubkey()),
+                ),
+            ];
+            env.process_ok(&instructions, &[&bot.owner]).await;
+
+            bots.push(bot);
+        }
+
+        Self {
+            env,
+            bots,
+            operator,
+            posts: Vec::new(),
+            comments: Vec::new(),
+            minted_by_claims: 0,
+            minted_by_crank: 0,
+        }
+    }
+
+    async fn run(&mut self, action: &Action) {
+        match *action {
+            Action::Post { bot } => {
+                let sequence = self.user(bot).await.post_count;
+                let instruction = instructions::create_post(
+                    FORUM_NAME,
+                    &self.bots[bot].nft_mint,
+                    self.bots[bot].owner.pubkey(),
+                    sequence,
+                    "post".to_string(),
+                    "general".to_string(),
+                    [0; 32],
+                    String::new(),
+                );
+
+                if self.spend(bot, instruction, |ap| ap.post).await {
+                    self.posts.push((bot, sequence));
+                }
+            }
+            Action::Comment { sender, post } | Action::OperatorComment { sender, post } => {
+                // sender_user and post_author must be distinct accounts
+                let Some((post_author, post_sequence)) =
+                    pick(&self.posts, post, |post| post.0 != sender)
+                else {
+                    return;
+                };
+
+                let instruction = match action {
+                    Action::Comment { .. } => instructions::add_comment(
+                        FORUM_NAME,
+                        &self.bots[post_author].user,
+                        post_sequence,
+                        &self.bots[sender].nft_mint,
+                        self.bots[sender].owner.pubkey(),
+                        "comment".to_string(),
+                        [0; 32],
+                        String::new(),
+                    ),
+                    _ => instructions::operator_add_comment(
+                        FORUM_NAME,
+                        &self.bots[post_author].user,
+                        post_sequence,
+                        &self.bots[sender].nft_mint,
+                        &self.operator.pubkey(),
+                        self.bots[sender].owner.pubkey(),
+                        "paid comment".to_string(),
+                        [0; 32],
+                        String::new(),
+                    ),
+                };
+
+                if self.spend(sender, instruction, |ap| ap.comment).await {
+                    let sequence = self.user(sender).await.comment_count;
+                    self.comments
+                        .push((post_author, post_sequence, sender, sequence));
+                }
+            }
+            Action::Reaction {
+                sender,
+                comment,
+                reaction_type,
+            } => {
+                // sender_user and comment_author_user must be distinct accounts
+                let Some((post_author, post_sequence, comment_author, comment_sequence)) =
+                    pick(&self.comments, comment, |comment| comment.2 != sender)
+                else {
+                    return;
+                };
+
+                let instruction = instructions::add_reaction(
+                    FORUM_NAME,
+                    &self.bots[post_author].user,
+                    post_sequence,
+                    &self.bots[comment_author].user,
+                    comment_sequence,
+                    &self.bots[sender].nft_mint,
+                    self.bots[sender].owner.pubkey(),
+                    reaction_type,
+                );
+
+                self.spend(sender, instruction, move |ap| match reaction_type {
+                    ReactionType::Upvote => ap.upvote,
+                    ReactionType::Downvote => ap.downvote,
+                    ReactionType::Like => ap.like,
+                    ReactionType::Banvote => ap.banvote,
+                })
+                .await;
+            }
+            Action::ResetActionPoints { bot } => {
+                let instruction = instructions::reset_user_action_points(
+                    FORUM_NAME,
+                    &self.bots[bot].nft_mint,
+                    self.bots[bot].owner.pubkey(),
+                );
+                self.process(bot, instruction).await.unwrap();
+            }
+            Action::Claim { bot } => {
+                let owner = self.bots[bot].owner.pubkey();
+                let balance_before = self.env.token_balance(&owner).await;
+                let distributed_before = self.forum().await.round_distributed;
+
+                let instruction = instructions::claim(
+                    FORUM_NAME,
+                    &self.bots[bot].nft_mint,
+                    owner,
+                    self.env.token_program,
+                    false,
+                );
+
+                if self.process(bot, instruction).await.is_ok() {
+                    let minted = self.env.token_balance(&owner).await - balance_before;
+                    let distributed = self.forum().await.round_distributed - distributed_before;
+
+                    assert_eq!(minted, distributed, "claim not counted in the round budget");
+                    self.minted_by_claims += minted;
+                }
+            }
+            Action::Deposit { bot, amount } | Action::Withdraw { bot, amount } => {
+                let owner = self.bots[bot].owner.pubkey();
+                let nft_mint = &self.bots[bot].nft_mint;
+                let operator = self.operator.pubkey();
+                let token_program = self.env.token_program;
+
+                let instruction = match action {
+                    Action::Deposit { .. } => instructions::deposit(
+                        FORUM_NAME,
+                        nft_mint,
+                        owner,
+                        operator,
+                        amount,
+                        token_program,
+                    ),
+                    _ => instructions::withdraw(
+                        FORUM_NAME,
+                        nft_mint,
+                        owner,
+                        operator,
+                        amount,
+                        token_program,
+                    ),
+                };
+
+                let _ = self.process(bot, instruction).await;
+            }
+            Action::AdvanceRound => {
+                let payer = self.env.payer().pubkey();
+                let balance_before = self.env.token_balance(&payer).await;
+
+                self.env.advance_round().await;
+
+                self.minted_by_crank += self.env.token_balance(&payer).await - balance_before;
+            }
+        }
+    }
+
+    // sends an instruction spending one action point of `bot`, read by
+    // `points`. it must fail without points left and take exactly one otherwise.
+    async fn spend(
+        &mut self,
+        bot: usize,
+        instruction: Instruction,
+        points: impl Fn(&ActionPoints) -> u64,
+    ) -> bool {
+        let forum = self.forum().await;
+        let user = self.user(bot).await;
+        let available =
+            if needs_action_point_reset(user.local_round_number, forum.round_status.round_number) {
+                points(&forum.round_config.default_action_points)
+            } else {
+                points(&user.action_points)
+            };
+
+        let succeeded = self.process(bot, instruction).await.is_ok();
+
+        if succeeded {
+            assert!(available > 0, "action without action points");
+            assert_eq!(
+                points(&self.user(bot).await.action_points),
+                available - 1,
+                "action did not take exactly one action point"
+            );
+        }
+
+        succeeded
+    }
+
+    async fn process(
+        &mut self,
+        bot: usize,
+        instruction: Instruction,
+    ) -> Result<(), BanksClientError> {
+        let owner = self.bots[bot].owner.insecure_clone();
+        self.env.process(&[instruction], &[&owner]).await
+    }
+
+    async fn forum(&mut self) -> Forum {
+        self.env.forum_account().await
+    }
+
+    async fn user(&mut self, bot: usize) -> User {
+        let user = self.bots[bot].user;
+        self.env.account(&user).await
+    }
+
+    async fn check_invariants(&mut self) {
+        let forum = self.forum().await;
+        let supply = self.env.mint_supply().await;
+
+        // the reward mint only mints claims and crank rewards
+        assert_eq!(
+            supply,
+            self.minted_by_claims + self.minted_by_crank,
+            "minted tokens not backed by claims"
+        );
+        assert!(supply <= forum.supply_config.max_supply);
+
+        assert!(
+            forum.round_distributed <= forum.round_status.round_max_distribution,
+            "claims exceed the round budget"
+        );
+
+        let payer = self.env.payer().pubkey();
+        let mut held = self.env.token_balance(&payer).await;
+
+        for bot in 0..BOTS {
+            let user = self.user(bot).await;
+            let defaults = &forum.round_config.default_action_points;
+
+            // an underflow wraps the points above the defaults
+            assert!(
+                user.action_points.post <= defaults.post
+                    && user.action_points.comment <= defaults.comment
+                    && user.action_points.upvote <= defaults.upvote
+                    && user.action_points.downvote <= defaults.downvote
+                    && user.action_points.like <= defaults.like
+                    && user.action_points.banvote <= defaults.banvote,
+                "action points out of range: {:?}",
+                user.action_points
+            );
+
+            let session_key = pda::operator_session(&self.bots[bot].user);
+            let session: OperatorSession = self.env.account(&session_key).await;
+            let vault_balance = self.env.token_balance(&session_key).await;
+
+            assert_eq!(
+                session.amount_for_user + session.amount_for_operator,
+                vault_balance,
+                "session accounting does not match the vault"
+            );
+
+            let owner = self.bots[bot].owner.pubkey();
+            held += self.env.token_balance(&owner).await + vault_balance;
+        }
+
+        assert_eq!(held, supply, "tokens held outside the known accounts");
+    }
+}
+
+// item `index` (modulo) among the items accepted by `filter`
+fn pick<T: Copy>(items: &[T], index: usize, filter: impl Fn(&T) -> bool) -> Option<T> {
+    let candidates: Vec<T> = items.iter().copied().filter(|item| filter(item)).collect();
+
+    if candidates.is_empty() {
+        None
+    } else {
+        Some(candidates[index % candidates.len()])
+    }
+}
+
+async fn run(max_supply: Option<u64>, actions: Vec<Action>) {
+    let mut harness = Harness::new(max_supply).await;
+
+    for action in &actions {
+        harness.run(action).await;
+        harness.check_invariants().await;
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn accounting_invariants_hold(
+        max_supply in prop_oneof![Just(None), (0..TOKEN_UNIT).prop_map(Some)],
+        actions in vec(action(), 1..48),
+    ) {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(run(max_supply, actions));
+    }
+}
@@ -0,0 +1,618 @@
+use anchor_lang::{AnchorDeserialize, AnchorSerialize, Discriminator, Space};
+use neobots::{
+    user_account_space, ActionPoints, Forum, ForumV0, NeobotsError, Operator, OperatorPool,
+    OperatorPoolV0, OperatorPrice, OperatorSession, OperatorSessionV0, OperatorV0, Post, PostV0,
+    RoundConfig, RoundDurationMode, User, UserCounter, UserCounterV0, ACCOUNT_VERSION,
+    INITIAL_ROUND_CONFIG, INITIAL_SUPPLY_CONFIG,
+};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use neobots_testkit::{assert_error, TestEnv};
+use solana_sdk::{account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+// space of the accounts of the first release, before the version byte
+const FORUM_V0_SPACE: usize = 8 + 377;
+const USER_V0_SPACE: usize = 8 + 522;
+const POST_V0_SPACE: usize = 8 + 80;
+const OPERATOR_V0_SPACE: usize = 8 + 97;
+const OPERATOR_SESSION_V0_SPACE: usize = 8 + 113;
+const USER_COUNTER_V0_SPACE: usize = 8 + 9;
+const OPERATOR_POOL_V0_SPACE: usize = 8 + 33;
+
+// account data written field by field in the layouts of the first release
+struct Fixture(Vec<u8>);
+
+impl Fixture {
+    fn new(discriminator: &[u8]) -> Self {
+        Self(discriminator.to_vec())
+    }
+
+    fn bytes(mut self, bytes: &[u8]) -> Self {
+        self.0.extend_from_slice(bytes);
+        self
+    }
+
+    fn pubkey(self, key: &Pubkey) -> Self {
+        self.bytes(key.as_ref())
+    }
+
+    fn u64(self, value: u64) -> Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    fn u32(self, value: u32) -> Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    fn string(self, value: &str) -> Self {
+        self.u32(value.len() as u32).bytes(value.as_bytes())
+    }
+
+    fn action_points(self, points: &ActionPoints) -> Self {
+        self.u64(points.post)
+            .u64(points.comment)
+            .u64(points.upvote)
+            .u64(points.downvote)
+            .u64(points.like)
+            .u64(points.banvote)
+    }
+
+    fn round_config(self, config: &RoundConfig) -> Self {
+        self.u64(config.round_duration)
+            .u64(config.round_min_distribution_rate)
+            .u64(config.round_max_distribution_rate)
+            .u64(config.k_comment_receiver)
+            .u64(config.k_comment)
+            .u64(config.k_quote)
+            .u64(config.k_reaction_giver)
+            .u64(config.k_reaction_receiver)
+            .u64(config.decay_factor)
+            .action_points(&config.default_action_points)
+    }
+
+    fn price(self, price: &OperatorPrice) -> Self {
+        self.u64(price.price_per_post)
+            .u64(price.price_per_comment)
+            .u64(price.price_per_like)
+            .u64(price.price_per_vote)
+    }
+
+    // the serialized fields, zero padded to the space of the account
+    fn finish(mut self, space: usize) -> Vec<u8> {
+        assert!(self.0.len() <= space, "fixture larger than its account");
+        self.0.resize(space, 0);
+        self.0
+    }
+}
+
+fn forum_v0(forum: &Forum) -> Vec<u8> {
+    let status = &forum.round_status;
+
+    Fixture::new(&Forum::DISCRIMINATOR)
+        .pubkey(&forum.admin)
+        .u64(forum.round_distributed)
+        .pubkey(&forum.mint)
+        .pubkey(&forum.nft_collection)
+        .u64(status.round_number)
+        .bytes(&status.round_start_time.to_le_bytes())
+        .u64(status.round_max_distribution)
+        .u64(status.round_distribution_rate)
+        .round_config(&forum.round_config)
+        .round_config(&forum.next_round_config)
+        .bytes(&[forum.bump])
+        .finish(FORUM_V0_SPACE)
+}
+
+// without interaction metrics nor operator
+fn user_v0(user: &User) -> Vec<u8> {
+    Fixture::new(&User::DISCRIMINATOR)
+        .pubkey(&user.nft_mint)
+        .u64(user.claimable_amount)
+        .u64(user.local_round_number)
+        .action_points(&user.action_points)
+        .u32(0)
+        .u32(user.post_count)
+        .u32(user.comment_count)
+        .u32(user.upvote_count)
+        .u32(user.downvote_count)
+        .u32(user.like_count)
+        .u32(user.banvote_count)
+        .u32(user.reaction_count)
+        .u64(user.received_upvote_count)
+        .u64(user.received_downvote_count)
+        .u64(user.received_like_count)
+        .u64(user.received_banvote_count)
+        .u64(user.received_reaction_count)
+        .u64(user.received_comment_count)
+        .string(&user.personality)
+        .string(&user.name)
+        .string(&user.thumb)
+        .bytes(&[0])
+        .bytes(&[user.bump])
+        .finish(USER_V0_SPACE)
+}
+
+fn post_v0(post: &Post) -> Vec<u8> {
+    Fixture::new(&Post::DISCRIMINATOR)
+        .pubkey(&post.author)
+        .bytes(&post.created_at.to_le_bytes())
+        .u32(post.sequence)
+        .bytes(&[post.interactable as u8])
+        .string(&post.content)
+        .bytes(&[post.bump])
+        .finish(POST_V0_SPACE)
+}
+
+fn operator_v0(operator: &Operator) -> Vec<u8> {
+    Fixture::new(&Operator::DISCRIMINATOR)
+        .pubkey(&operator.authority)
+        .price(&operator.price)
+        .price(&operator.next_round_price)
+        .bytes(&[operator.bump])
+        .finish(OPERATOR_V0_SPACE)
+}
+
+fn operator_session_v0(session: &OperatorSession) -> Vec<u8> {
+    Fixture::new(&OperatorSession::DISCRIMINATOR)
+        .pubkey(&session.user)
+        .pubkey(&session.operator)
+        .pubkey(&session.vault)
+        .u64(session.amount_for_user)
+        .u64(session.amount_for_operator)
+        .bytes(&[session.bump])
+        .finish(OPERATOR_SESSION_V0_SPACE)
+}
+
+fn user_counter_v0(counter: &UserCounter) -> Vec<u8> {
+    Fixture::new(&UserCounter::DISCRIMINATOR)
+        .u64(counter.count)
+        .bytes(&[counter.bump])
+        .finish(USER_COUNTER_V0_SPACE)
+}
+
+fn operator_pool_v0(pool: &OperatorPool) -> Vec<u8> {
+    Fixture::new(&OperatorPool::DISCRIMINATOR)
+        .pubkey(&pool.authority)
+        .bytes(&[pool.bump])
+        .finish(OPERATOR_POOL_V0_SPACE)
+}
+
+async fn raw_account(env: &mut TestEnv, key: &Pubkey) -> Account {
+    env.context
+        .banks_client
+        .get_account(*key)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn migrate(
+    env: &mut TestEnv,
+    account: Pubkey,
+    user: Option<Pubkey>,
+    nft_mint: Option<Pubkey>,
+    authority: &Keypair,
+) -> Result<(), solana_program_test::BanksClientError> {
+    let instruction =
+        instructions::migrate_account(FORUM_NAME, account, user, nft_mint, authority.pubkey());
+
+    if authority.pubkey() == env.payer().pubkey() {
+        env.process(&[instruction], &[]).await
+    } else {
+        env.process(&[instruction], &[authority]).await
+    }
+}
+
+fn price(amount: u64) -> OperatorPrice {
+    OperatorPrice {
+        price_per_post: amount,
+        price_per_comment: amount,
+        price_per_like: amount,
+        price_per_vote: amount,
+    }
+}
+
+#[test]
+fn legacy_layouts_read_the_baseline_fixtures() {
+    let operator = Operator {
+        authority: Pubkey::new_unique(),
+        price: price(1),
+        next_round_price: price(2),
+        bump: 255,
+        version: ACCOUNT_VERSION,
+    };
+    let data = operator_v0(&operator);
+    let legacy = OperatorV0::deserialize(&mut &data[8..]).unwrap();
+    assert_eq!(
+        legacy.try_to_vec().unwrap(),
+        data[8..OPERATOR_V0_SPACE].to_vec()
+    );
+    assert_eq!(Operator::from(legacy).next_round_price.price_per_post, 2);
+
+    let session = OperatorSession {
+        user: Pubkey::new_unique(),
+        operator: Pubkey::new_unique(),
+        vault: Pubkey::new_unique(),
+        amount_for_user: 1,
+        amount_for_operator: 2,
+        bump: 255,
+        version: ACCOUNT_VERSION,
+    };
+    let data = operator_session_v0(&session);
+    let legacy = OperatorSessionV0::deserialize(&mut &data[8..]).unwrap();
+    assert_eq!(
+        legacy.try_to_vec().unwrap(),
+        data[8..OPERATOR_SESSION_V0_SPACE].to_vec()
+    );
+    assert_eq!(OperatorSession::from(legacy).amount_for_operator, 2);
+
+    let counter = UserCounter {
+        count: 7,
+        bump: 253,
+        version: ACCOUNT_VERSION,
+    };
+    let data = user_counter_v0(&counter);
+    let legacy = UserCounterV0::deserialize(&mut &data[8..]).unwrap();
+    assert_eq!(legacy.try_to_vec().unwrap(), data[8..].to_vec());
+    let counter = UserCounter::from(legacy);
+    assert_eq!((counter.count, counter.bump), (7, 253));
+    assert_eq!(counter.version, ACCOUNT_VERSION);
+
+    let pool = OperatorPool {
+        authority: Pubkey::new_unique(),
+        bump: 252,
+        version: ACCOUNT_VERSION,
+    };
+    let data = operator_pool_v0(&pool);
+    let legacy = OperatorPoolV0::deserialize(&mut &data[8..]).unwrap();
+    assert_eq!(legacy.try_to_vec().unwrap(), data[8..].to_vec());
+    assert_eq!(OperatorPool::from(legacy).authority, pool.authority);
+
+    // the content commitment did not exist
+    let post = Post {
+        author: Pubkey::new_unique(),
+        created_at: 1_700_000_000,
+        sequence: 3,
+        interactable: true,
+        content: "hello".to_string(),
+        content_hash: [1; 32],
+        content_uri: "posts/3".to_string(),
+        bump: 254,
+        version: ACCOUNT_VERSION,
+    };
+    let data = post_v0(&post);
+    let post = Post::from(PostV0::deserialize(&mut &data[8..]).unwrap());
+    assert_eq!(post.sequence, 3);
+    assert_eq!(post.content, "hello");
+    assert_eq!(post.content_hash, [0; 32]);
+    assert!(post.content_uri.is_empty());
+    assert_eq!(post.bump, 254);
+    assert_eq!(post.version, ACCOUNT_VERSION);
+
+    // the current layouts only grew
+    assert!(FORUM_V0_SPACE < 8 + Forum::INIT_SPACE);
+    assert!(USER_V0_SPACE < 8 + User::INIT_SPACE);
+    assert!(POST_V0_SPACE < 8 + Post::INIT_SPACE);
+    assert_eq!(OPERATOR_V0_SPACE, 8 + Operator::INIT_SPACE - 1);
+    assert_eq!(
+        OPERATOR_SESSION_V0_SPACE,
+        8 + OperatorSession::INIT_SPACE - 1
+    );
+    assert_eq!(USER_COUNTER_V0_SPACE, 8 + UserCounter::INIT_SPACE - 1);
+    assert_eq!(OPERATOR_POOL_V0_SPACE, 8 + OperatorPool::INIT_SPACE - 1);
+}
+
+#[tokio::test]
+async fn migrate_forum_by_admin() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let forum_key = env.forum();
+    let before = env.forum_account().await;
+
+    env.set_account_data(&forum_key, forum_v0(&before)).await;
+    let legacy =
+        ForumV0::deserialize(&mut &raw_account(&mut env, &forum_key).await.data[8..]).unwrap();
+    assert_eq!(legacy.admin, before.admin);
+    assert_eq!(
+        legacy.round_config.k_comment,
+        INITIAL_ROUND_CONFIG.k_comment
+    );
+
+    migrate(&mut env, forum_key, None, None, &payer)
+        .await
+        .unwrap();
+
+    let account = raw_account(&mut env, &forum_key).await;
+    assert_eq!(account.data.len(), 8 + Forum::INIT_SPACE);
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.version, ACCOUNT_VERSION);
+    assert_eq!(forum.admin, before.admin);
+    assert_eq!(forum.mint, before.mint);
+    assert_eq!(
+        forum.round_status.round_start_time,
+        before.round_status.round_start_time
+    );
+    assert_eq!(
+        forum.round_config.round_duration,
+        before.round_config.round_duration
+    );
+
+    // the fields added since the first release get their default
+    assert_eq!(
+        forum.round_status.round_duration,
+        before.round_config.round_duration
+    );
+    for config in [&forum.round_config, &forum.next_round_config] {
+        assert_eq!(config.round_duration_mode, RoundDurationMode::Fixed);
+        assert_eq!(config.vesting_duration, 0);
+        assert_eq!(config.claim_window_rounds, 0);
+        assert_eq!(config.crank_reward, 0);
+    }
+    assert_eq!(
+        forum.supply_config.max_supply,
+        INITIAL_SUPPLY_CONFIG.max_supply
+    );
+    assert_eq!(
+        forum.supply_config.initial_round_emission,
+        before.round_status.round_max_distribution
+    );
+    assert_eq!(forum.round_activity.active_users, 0);
+    assert_eq!(forum.last_round_activity.active_users, 0);
+    assert_eq!(forum.last_advance_slot, 0);
+
+    // the admin topped up the rent of the larger account
+    let rent = env.context.banks_client.get_rent().await.unwrap();
+    assert!(account.lamports >= rent.minimum_balance(account.data.len()));
+
+    // the migrated forum keeps advancing, without crank reward
+    env.advance_round().await;
+    assert_eq!(env.forum_account().await.round_status.round_number, 1);
+    assert_eq!(env.token_balance(&payer.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn migrate_user_by_owner() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+
+    let before = env.user_account(&bob).await;
+    env.set_account_data(&bob.user, user_v0(&before)).await;
+
+    // the owner is the holder of the NFT of the user
+    assert_error(
+        migrate(&mut env, bob.user, None, None, &bob.owner).await,
+        NeobotsError::InvalidInput,
+    );
+    assert_error(
+        migrate(&mut env, bob.user, None, Some(alice.nft_mint), &bob.owner).await,
+        NeobotsError::InvalidInput,
+    );
+    migrate(&mut env, bob.user, None, Some(bob.nft_mint), &bob.owner)
+        .await
+        .unwrap();
+
+    let user = env.user_account(&bob).await;
+    assert_eq!(user.version, ACCOUNT_VERSION);
+    assert_eq!(user.owner, bob.owner.pubkey());
+    assert_eq!(user.name, "bob");
+    assert_eq!(user.comment_count, 1);
+    assert_eq!(user.claimable_amount, before.claimable_amount);
+    assert_eq!(user.last_active_round, None);
+    assert!(user.reward_buckets.is_empty());
+    assert_eq!(
+        raw_account(&mut env, &bob.user).await.data.len(),
+        user_account_space(&user.personality, &user.name, &user.thumb)
+    );
+
+    // the migrated user keeps acting and claiming
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    assert_eq!(env.user_account(&bob).await.comment_count, 2);
+    env.claim(&bob).await.unwrap();
+}
+
+#[tokio::test]
+async fn migrate_post_by_author_owner() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+    let post_key = env.create_post(&bot).await.unwrap();
+    let before: Post = env.account(&post_key).await;
+    let user = env.user_account(&bot).await;
+
+    env.set_account_data(&post_key, post_v0(&before)).await;
+    env.set_account_data(&bot.user, user_v0(&user)).await;
+
+    // the owner of a post is the owner of its author, once migrated
+    assert_error(
+        migrate(&mut env, post_key, None, None, &bot.owner).await,
+        NeobotsError::InvalidInput,
+    );
+    assert_error(
+        migrate(&mut env, post_key, Some(bot.user), None, &bot.owner).await,
+        NeobotsError::InvalidInput,
+    );
+    migrate(&mut env, bot.user, None, Some(bot.nft_mint), &bot.owner)
+        .await
+        .unwrap();
+    migrate(&mut env, post_key, Some(bot.user), None, &bot.owner)
+        .await
+        .unwrap();
+
+    let post: Post = env.account(&post_key).await;
+    assert_eq!(post.version, ACCOUNT_VERSION);
+    assert_eq!(post.author, bot.user);
+    assert_eq!(post.content, before.content);
+    assert_eq!(post.created_at, before.created_at);
+    assert_eq!(post.content_hash, [0; 32]);
+    assert!(post.content_uri.is_empty());
+    assert_eq!(
+        raw_account(&mut env, &post_key).await.data.len(),
+        8 + Post::INIT_SPACE
+    );
+}
+
+#[tokio::test]
+async fn migrate_operator_and_session() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let wallet = env.new_wallet().await;
+    let bot = env.create_bot("alice").await;
+
+    let instruction = instructions::initialize_operator_pool(FORUM_NAME, payer.pubkey());
+    env.process_ok(&[instruction], &[]).await;
+
+    let instruction = instructions::initialize_operator(
+        FORUM_NAME,
+        wallet.pubkey(),
+        "operator".to_string(),
+        4,
+        3,
+        2,
+        1,
+    );
+    env.process_ok(&[instruction], &[&wallet]).await;
+
+    let instruction = instructions::initialize_operator_session(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        wallet.pubkey(),
+        env.token_program,
+    );
+    env.process_ok(&[instruction], &[&bot.owner]).await;
+
+    let operator_key = pda::operator(&wallet.pubkey());
+    let session_key = pda::operator_session(&bot.user);
+    let operator: Operator = env.account(&operator_key).await;
+    let session: OperatorSession = env.account(&session_key).await;
+    env.set_account_data(&operator_key, operator_v0(&operator))
+        .await;
+    env.set_account_data(&session_key, operator_session_v0(&session))
+        .await;
+
+    migrate(&mut env, operator_key, None, None, &wallet)
+        .await
+        .unwrap();
+    migrate(&mut env, session_key, Some(bot.user), None, &bot.owner)
+        .await
+        .unwrap();
+
+    let operator: Operator = env.account(&operator_key).await;
+    assert_eq!(operator.version, ACCOUNT_VERSION);
+    assert_eq!(operator.authority, wallet.pubkey());
+    assert_eq!(operator.price.price_per_post, 4);
+
+    let session: OperatorSession = env.account(&session_key).await;
+    assert_eq!(session.version, ACCOUNT_VERSION);
+    assert_eq!(session.user, bot.user);
+    assert_eq!(
+        raw_account(&mut env, &session_key).await.data.len(),
+        8 + OperatorSession::INIT_SPACE
+    );
+}
+
+#[tokio::test]
+async fn migrate_user_counter_and_operator_pool() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let stranger = env.new_wallet().await;
+    env.create_bot("alice").await;
+
+    let instruction = instructions::initialize_operator_pool(FORUM_NAME, payer.pubkey());
+    env.process_ok(&[instruction], &[]).await;
+
+    let counter_key = pda::user_counter();
+    let pool_key = pda::operator_pool();
+    let counter: UserCounter = env.account(&counter_key).await;
+    let pool: OperatorPool = env.account(&pool_key).await;
+    env.set_account_data(&counter_key, user_counter_v0(&counter))
+        .await;
+    env.set_account_data(&pool_key, operator_pool_v0(&pool))
+        .await;
+
+    // nobody but the admin owns the counter
+    assert_error(
+        migrate(&mut env, counter_key, None, None, &stranger).await,
+        NeobotsError::AccessDenied,
+    );
+    migrate(&mut env, counter_key, None, None, &payer)
+        .await
+        .unwrap();
+    migrate(&mut env, pool_key, None, None, &payer)
+        .await
+        .unwrap();
+
+    let counter: UserCounter = env.account(&counter_key).await;
+    assert_eq!(counter.version, ACCOUNT_VERSION);
+    assert_eq!(counter.count, 1);
+    assert_eq!(
+        raw_account(&mut env, &counter_key).await.data.len(),
+        8 + UserCounter::INIT_SPACE
+    );
+
+    let pool: OperatorPool = env.account(&pool_key).await;
+    assert_eq!(pool.version, ACCOUNT_VERSION);
+    assert_eq!(pool.authority, payer.pubkey());
+
+    // the migrated counter keeps counting
+    env.create_bot("bob").await;
+    let counter: UserCounter = env.account(&counter_key).await;
+    assert_eq!(counter.count, 2);
+}
+
+#[tokio::test]
+async fn migrate_account_requires_owner_or_admin() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let stranger = env.new_wallet().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+
+    for bot in [&alice, &bob] {
+        let user = env.user_account(bot).await;
+        env.set_account_data(&bot.user, user_v0(&user)).await;
+    }
+
+    assert_error(
+        migrate(&mut env, alice.user, None, Some(alice.nft_mint), &stranger).await,
+        NeobotsError::AccessDenied,
+    );
+    assert_error(
+        migrate(&mut env, alice.user, None, Some(alice.nft_mint), &bob.owner).await,
+        NeobotsError::AccessDenied,
+    );
+
+    // the admin migrates any account
+    migrate(&mut env, bob.user, None, Some(bob.nft_mint), &payer)
+        .await
+        .unwrap();
+    let user = env.user_account(&bob).await;
+    assert_eq!(user.version, ACCOUNT_VERSION);
+    assert_eq!(user.owner, bob.owner.pubkey());
+}
+
+#[tokio::test]
+async fn migrate_account_rejects_current_and_unknown_accounts() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let bot = env.create_bot("alice").await;
+
+    assert_error(
+        migrate(&mut env, bot.user, None, Some(bot.nft_mint), &payer).await,
+        NeobotsError::AccountUpToDate,
+    );
+    assert_error(
+        migrate(&mut env, pda::user_counter(), None, None, &payer).await,
+        NeobotsError::AccountUpToDate,
+    );
+
+    // no legacy layout
+    env.advance_round().await;
+    let history = pda::round_history(&env.forum());
+    assert_error(
+        migrate(&mut env, history, None, None, &payer).await,
+        NeobotsError::InvalidAccountType,
+    );
+}
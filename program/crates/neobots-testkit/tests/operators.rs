@@ -0,0 +1,527 @@
+use anchor_spl::token_2022::spl_token_2022;
+use neobots::{
+    NeobotsError, Operator, OperatorPool, OperatorSession, INITIAL_ROUND_CONFIG, TOKEN_DECIMALS,
+};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use neobots_testkit::{assert_error, Bot, TestEnv};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+const PRICE_PER_COMMENT: u64 = 1_000_000;
+
+async fn register_operator(env: &mut TestEnv) -> Keypair {
+    let wallet = env.new_wallet().await;
+
+    let instruction = instructions::initialize_operator(
+        FORUM_NAME,
+        wallet.pubkey(),
+        "operator".to_string(),
+        2 * PRICE_PER_COMMENT,
+        PRICE_PER_COMMENT,
+        PRICE_PER_COMMENT / 10,
+        PRICE_PER_COMMENT / 10,
+    );
+    env.process_ok(&[instruction], &[&wallet]).await;
+
+    wallet
+}
+
+/// alice posted, bob holds the reward of a comment on it and opened a session
+/// with `operator`.
+async fn setup() -> (TestEnv, Bot, Bot, Keypair) {
+    setup_in(TestEnv::new().await).await
+}
+
+async fn setup_in(mut env: TestEnv) -> (TestEnv, Bot, Bot, Keypair) {
+    let payer = env.payer().pubkey();
+
+    let instruction = instructions::initialize_operator_pool(FORUM_NAME, payer);
+    env.process_ok(&[instruction], &[]).await;
+    let operator = register_operator(&mut env).await;
+
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    env.claim(&bob).await.unwrap();
+
+    let owner = bob.owner.pubkey();
+    let instructions = [
+        instructions::initialize_operator_session(
+            FORUM_NAME,
+            &bob.nft_mint,
+            owner,
+            operator.pubkey(),
+            env.token_program,
+        ),
+        instructions::set_operator(FORUM_NAME, &bob.nft_mint, owner, operator.pubkey()),
+        instructions::set_user_operator(
+            FORUM_NAME,
+            &bob.nft_mint,
+            owner,
+            pda::operator(&operator.pubkey()),
+        ),
+    ];
+    env.process_ok(&instructions, &[&bob.owner]).await;
+
+    (env, alice, bob, operator)
+}
+
+fn deposit(env: &TestEnv, bot: &Bot, operator: &Keypair, amount: u64) -> Instruction {
+    instructions::deposit(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        operator.pubkey(),
+        amount,
+        env.token_program,
+    )
+}
+
+fn withdraw(env: &TestEnv, bot: &Bot, operator: &Keypair, amount: u64) -> Instruction {
+    instructions::withdraw(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        operator.pubkey(),
+        amount,
+        env.token_program,
+    )
+}
+
+/// Paid comment of `sender` on the first post of `post_author`, signed by
+/// `signer`.
+fn operator_comment(
+    post_author: &Bot,
+    sender: &Bot,
+    operator: &Keypair,
+    signer: &Keypair,
+) -> Instruction {
+    instructions::operator_add_comment(
+        FORUM_NAME,
+        &post_author.user,
+        0,
+        &sender.nft_mint,
+        &operator.pubkey(),
+        signer.pubkey(),
+        "paid comment".to_string(),
+        [0; 32],
+        String::new(),
+    )
+}
+
+/// Transfer from the ATA of `from` straight to the session vault of `to`,
+/// outside of the program.
+async fn send_to_vault(env: &mut TestEnv, from: &Bot, to: &Bot, amount: u64) {
+    let mint = pda::mint();
+    let instruction = spl_token_2022::instruction::transfer_checked(
+        &env.token_program,
+        &pda::associated_token(&from.owner.pubkey(), &mint, &env.token_program),
+        &mint,
+        &pda::session_vault(&to.user, &mint, &env.token_program),
+        &from.owner.pubkey(),
+        &[],
+        amount,
+        TOKEN_DECIMALS,
+    )
+    .unwrap();
+    env.process_ok(&[instruction], &[&from.owner]).await;
+}
+
+async fn session(env: &mut TestEnv, bot: &Bot) -> OperatorSession {
+    env.account(&pda::operator_session(&bot.user)).await
+}
+
+#[tokio::test]
+async fn initialize_operator() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer().pubkey();
+
+    let instruction = instructions::initialize_operator_pool(FORUM_NAME, payer);
+    env.process_ok(&[instruction], &[]).await;
+
+    let pool: OperatorPool = env.account(&pda::operator_pool()).await;
+    assert_eq!(pool.authority, payer);
+
+    let wallet = register_operator(&mut env).await;
+    let operator: Operator = env.account(&pda::operator(&wallet.pubkey())).await;
+
+    assert_eq!(operator.authority, wallet.pubkey());
+    assert_eq!(operator.price.price_per_comment, PRICE_PER_COMMENT);
+    assert_eq!(
+        operator.next_round_price.price_per_comment,
+        PRICE_PER_COMMENT
+    );
+}
+
+#[tokio::test]
+async fn initialize_operator_session() {
+    let (mut env, _, bob, operator) = setup().await;
+    let session = session(&mut env, &bob).await;
+
+    assert_eq!(session.user, bob.user);
+    assert_eq!(session.operator, pda::operator(&operator.pubkey()));
+    assert_eq!(
+        session.vault,
+        pda::session_vault(&bob.user, &pda::mint(), &env.token_program)
+    );
+    assert_eq!(session.amount_for_user, 0);
+
+    let instruction = instructions::initialize_operator_session(
+        FORUM_NAME,
+        &bob.nft_mint,
+        bob.owner.pubkey(),
+        operator.pubkey(),
+        env.token_program,
+    );
+    assert_error(
+        env.process(&[instruction], &[&bob.owner]).await,
+        NeobotsError::OperatorSessionAlreadyInitialized,
+    );
+}
+
+#[tokio::test]
+async fn deposit_requires_session() {
+    let (mut env, alice, _, operator) = setup().await;
+
+    // alice earned the receiver reward but never opened a session
+    env.claim(&alice).await.unwrap();
+
+    assert_error(
+        env.process(&[deposit(&env, &alice, &operator, 1)], &[&alice.owner])
+            .await,
+        NeobotsError::OperatorSessionNotInitialized,
+    );
+}
+
+#[tokio::test]
+async fn deposit_and_withdraw() {
+    let (mut env, _, bob, operator) = setup().await;
+    let owner = bob.owner.pubkey();
+    let vault_owner = pda::operator_session(&bob.user);
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, 3 * PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    assert_eq!(
+        session(&mut env, &bob).await.amount_for_user,
+        3 * PRICE_PER_COMMENT
+    );
+    assert_eq!(env.token_balance(&vault_owner).await, 3 * PRICE_PER_COMMENT);
+    assert_eq!(
+        env.token_balance(&owner).await,
+        INITIAL_ROUND_CONFIG.k_comment - 3 * PRICE_PER_COMMENT
+    );
+
+    env.process_ok(
+        &[withdraw(&env, &bob, &operator, PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    assert_eq!(
+        session(&mut env, &bob).await.amount_for_user,
+        2 * PRICE_PER_COMMENT
+    );
+    assert_eq!(env.token_balance(&vault_owner).await, 2 * PRICE_PER_COMMENT);
+
+    assert_error(
+        env.process(
+            &[withdraw(&env, &bob, &operator, 3 * PRICE_PER_COMMENT)],
+            &[&bob.owner],
+        )
+        .await,
+        NeobotsError::InsufficientFunds,
+    );
+}
+
+#[tokio::test]
+async fn operator_add_comment_charges_the_session() {
+    let (mut env, alice, bob, operator) = setup().await;
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, 2 * PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    // the operator wallet signs for bob
+    for _ in 0..2 {
+        env.process_ok(
+            &[operator_comment(&alice, &bob, &operator, &operator)],
+            &[&operator],
+        )
+        .await;
+    }
+
+    let session = session(&mut env, &bob).await;
+    assert_eq!(session.amount_for_user, 0);
+    assert_eq!(session.amount_for_operator, 2 * PRICE_PER_COMMENT);
+    assert_eq!(env.user_account(&bob).await.comment_count, 3);
+
+    assert_error(
+        env.process(
+            &[operator_comment(&alice, &bob, &operator, &operator)],
+            &[&operator],
+        )
+        .await,
+        NeobotsError::InsufficientFunds,
+    );
+}
+
+#[tokio::test]
+async fn operator_add_comment_requires_owner_or_operator() {
+    let (mut env, alice, bob, operator) = setup().await;
+    let stranger = env.new_wallet().await;
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, 2 * PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    assert_error(
+        env.process(
+            &[operator_comment(&alice, &bob, &operator, &stranger)],
+            &[&stranger],
+        )
+        .await,
+        NeobotsError::NFTNotOwned,
+    );
+
+    // the owner can still send it
+    env.process_ok(
+        &[operator_comment(&alice, &bob, &operator, &bob.owner)],
+        &[&bob.owner],
+    )
+    .await;
+    assert_eq!(
+        session(&mut env, &bob).await.amount_for_operator,
+        PRICE_PER_COMMENT
+    );
+}
+
+#[tokio::test]
+async fn operator_add_comment_rejects_other_operator() {
+    let (mut env, alice, bob, operator) = setup().await;
+    let other_operator = register_operator(&mut env).await;
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    assert_error(
+        env.process(
+            &[operator_comment(
+                &alice,
+                &bob,
+                &other_operator,
+                &other_operator,
+            )],
+            &[&other_operator],
+        )
+        .await,
+        NeobotsError::OperatorKeyMismatch,
+    );
+}
+
+#[tokio::test]
+async fn close_user_requires_empty_session() {
+    let (mut env, _, bob, operator) = setup().await;
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    let instruction = instructions::close_user(
+        FORUM_NAME,
+        &bob.nft_mint,
+        bob.owner.pubkey(),
+        env.token_program,
+        true,
+    );
+    assert_error(
+        env.process(&[instruction.clone()], &[&bob.owner]).await,
+        NeobotsError::OperatorSessionNotEmpty,
+    );
+
+    env.process_ok(
+        &[withdraw(&env, &bob, &operator, PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    // the session and its vault are closed with the user
+    let instruction_without_session = instructions::close_user(
+        FORUM_NAME,
+        &bob.nft_mint,
+        bob.owner.pubkey(),
+        env.token_program,
+        false,
+    );
+    assert_error(
+        env.process(&[instruction_without_session], &[&bob.owner])
+            .await,
+        NeobotsError::InvalidInput,
+    );
+    env.process_ok(&[instruction], &[&bob.owner]).await;
+
+    let session = pda::operator_session(&bob.user);
+    let vault = pda::session_vault(&bob.user, &pda::mint(), &env.token_program);
+    assert!(!env.exists(&bob.user).await);
+    assert!(!env.exists(&session).await);
+    assert!(!env.exists(&vault).await);
+}
+
+#[tokio::test]
+async fn withdraw_after_transfer_requires_rebind() {
+    let (mut env, _, bob, operator) = setup().await;
+    let new_owner = env.new_wallet().await;
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+    env.transfer_asset(&bob.nft_mint, &bob.owner, &new_owner.pubkey())
+        .await;
+
+    // neither the new holder nor the previous owner moves the deposit of the
+    // stale binding
+    let instruction = instructions::withdraw(
+        FORUM_NAME,
+        &bob.nft_mint,
+        new_owner.pubkey(),
+        operator.pubkey(),
+        PRICE_PER_COMMENT,
+        env.token_program,
+    );
+    assert_error(
+        env.process(&[instruction.clone()], &[&new_owner]).await,
+        NeobotsError::OwnerMismatch,
+    );
+    assert_error(
+        env.process(
+            &[withdraw(&env, &bob, &operator, PRICE_PER_COMMENT)],
+            &[&bob.owner],
+        )
+        .await,
+        NeobotsError::NFTNotOwned,
+    );
+
+    let rebind = instructions::rebind_owner(FORUM_NAME, &bob.nft_mint, new_owner.pubkey());
+    env.process_ok(&[rebind], &[&new_owner]).await;
+    env.process_ok(&[instruction], &[&new_owner]).await;
+
+    assert_eq!(session(&mut env, &bob).await.amount_for_user, 0);
+    assert_eq!(
+        env.token_balance(&new_owner.pubkey()).await,
+        PRICE_PER_COMMENT
+    );
+}
+
+#[tokio::test]
+async fn rebind_owner_resets_the_session_operator() {
+    let (mut env, _, bob, operator) = setup().await;
+    let new_owner = env.new_wallet().await;
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+    env.transfer_asset(&bob.nft_mint, &bob.owner, &new_owner.pubkey())
+        .await;
+
+    let instruction = instructions::rebind_owner(FORUM_NAME, &bob.nft_mint, new_owner.pubkey());
+    env.process_ok(&[instruction], &[&new_owner]).await;
+
+    assert_eq!(env.user_account(&bob).await.operator, None);
+    let session = session(&mut env, &bob).await;
+    assert_eq!(session.operator, Pubkey::default());
+
+    // the deposit follows the NFT
+    assert_eq!(session.amount_for_user, PRICE_PER_COMMENT);
+    let instruction = instructions::withdraw(
+        FORUM_NAME,
+        &bob.nft_mint,
+        new_owner.pubkey(),
+        operator.pubkey(),
+        PRICE_PER_COMMENT,
+        env.token_program,
+    );
+    env.process_ok(&[instruction], &[&new_owner]).await;
+    assert_eq!(
+        env.token_balance(&new_owner.pubkey()).await,
+        PRICE_PER_COMMENT
+    );
+}
+
+#[tokio::test]
+async fn close_user_forfeits_the_operator_balance() {
+    let (mut env, alice, bob, operator) = setup().await;
+    let owner = bob.owner.pubkey();
+
+    env.process_ok(
+        &[deposit(&env, &bob, &operator, 2 * PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+    env.process_ok(
+        &[operator_comment(&alice, &bob, &operator, &operator)],
+        &[&operator],
+    )
+    .await;
+    env.process_ok(
+        &[withdraw(&env, &bob, &operator, PRICE_PER_COMMENT)],
+        &[&bob.owner],
+    )
+    .await;
+
+    // stray tokens sent to the vault do not block the close
+    env.claim(&alice).await.unwrap();
+    send_to_vault(&mut env, &alice, &bob, 1_000).await;
+
+    let supply = env.mint_supply().await;
+    let balance = env.token_balance(&owner).await;
+
+    let instruction =
+        instructions::close_user(FORUM_NAME, &bob.nft_mint, owner, env.token_program, true);
+    env.process_ok(&[instruction], &[&bob.owner]).await;
+
+    // the charged balance is burned, the stray tokens go to the owner
+    assert_eq!(env.mint_supply().await, supply - PRICE_PER_COMMENT);
+    assert_eq!(env.token_balance(&owner).await, balance + 1_000);
+
+    let vault = pda::session_vault(&bob.user, &pda::mint(), &env.token_program);
+    assert!(!env.exists(&pda::operator_session(&bob.user)).await);
+    assert!(!env.exists(&vault).await);
+}
+
+#[tokio::test]
+async fn close_user_harvests_the_withheld_fees_of_the_vault() {
+    let (mut env, alice, bob, _) = setup_in(TestEnv::new_token_2022(100, 1_000_000).await).await;
+
+    // the transfer fee is withheld in the vault
+    env.claim(&alice).await.unwrap();
+    send_to_vault(&mut env, &alice, &bob, 100_000).await;
+
+    let instruction = instructions::close_user(
+        FORUM_NAME,
+        &bob.nft_mint,
+        bob.owner.pubkey(),
+        env.token_program,
+        true,
+    );
+    env.process_ok(&[instruction], &[&bob.owner]).await;
+
+    let vault = pda::session_vault(&bob.user, &pda::mint(), &env.token_program);
+    assert!(!env.exists(&vault).await);
+    assert!(!env.exists(&bob.user).await);
+}
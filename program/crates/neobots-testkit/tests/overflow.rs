@@ -0,0 +1,149 @@
+//! Accounting at the numeric edges: the instruction fails with `MathOverflow`
+//! instead of wrapping or clamping.
+
+use anchor_lang::error::Error;
+use neobots::{calculate_reward, distribute_reward, Forum, NeobotsError, ReactionType};
+use neobots_client::{instructions, FORUM_NAME};
+use neobots_testkit::{assert_error, TestEnv};
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+
+#[tokio::test]
+async fn checked_helpers_return_math_overflow() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+
+    let mut forum = env.forum_account().await;
+    forum.round_status.round_distribution_rate = u64::MAX;
+    assert_eq!(
+        calculate_reward(&forum, u64::MAX).unwrap_err(),
+        Error::from(NeobotsError::MathOverflow)
+    );
+
+    let mut user = env.user_account(&alice).await;
+    user.claimable_amount = u64::MAX;
+    let result = distribute_reward(
+        Pubkey::new_unique(),
+        &mut user,
+        &forum,
+        1,
+        "test".to_string(),
+    );
+    assert_eq!(result.unwrap_err(), Error::from(NeobotsError::MathOverflow));
+    assert_eq!(user.claimable_amount, u64::MAX);
+}
+
+#[tokio::test]
+async fn reward_overflow() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    env.create_post(&alice).await.unwrap();
+
+    let mut user = env.user_account(&bob).await;
+    user.claimable_amount = u64::MAX;
+    env.set_account(&bob.user, &user).await;
+
+    let result = env.add_comment(&bob, &alice, 0).await;
+    assert_error(result, NeobotsError::MathOverflow);
+    assert_eq!(env.user_account(&bob).await.claimable_amount, u64::MAX);
+}
+
+#[tokio::test]
+async fn receiver_reward_overflow() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    env.create_post(&alice).await.unwrap();
+
+    let mut user = env.user_account(&alice).await;
+    user.claimable_amount = u64::MAX - 1;
+    env.set_account(&alice.user, &user).await;
+
+    let result = env.add_comment(&bob, &alice, 0).await;
+    assert_error(result, NeobotsError::MathOverflow);
+}
+
+#[tokio::test]
+async fn post_count_overflow() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+
+    let mut user = env.user_account(&alice).await;
+    user.post_count = u32::MAX;
+    env.set_account(&alice.user, &user).await;
+
+    let result = env.create_post(&alice).await.map(|_| ());
+    assert_error(result, NeobotsError::MathOverflow);
+}
+
+#[tokio::test]
+async fn round_activity_overflow() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    env.create_post(&alice).await.unwrap();
+
+    let mut forum = env.forum_account().await;
+    forum.round_activity.comment_count = u64::MAX;
+    env.set_account(&env.forum(), &forum).await;
+
+    let result = env.add_comment(&bob, &alice, 0).await;
+    assert_error(result, NeobotsError::MathOverflow);
+}
+
+#[tokio::test]
+async fn reaction_counter_overflow() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    let carol = env.create_bot("carol").await;
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+
+    let mut user = env.user_account(&bob).await;
+    user.received_reaction_count = u64::MAX;
+    env.set_account(&bob.user, &user).await;
+
+    let instruction = instructions::add_reaction(
+        FORUM_NAME,
+        &alice.user,
+        0,
+        &bob.user,
+        1,
+        &carol.nft_mint,
+        carol.owner.pubkey(),
+        ReactionType::Like,
+    );
+    let result = env.process(&[instruction], &[&carol.owner]).await;
+    assert_error(result, NeobotsError::MathOverflow);
+}
+
+#[tokio::test]
+async fn round_number_overflow() {
+    let mut env = TestEnv::new().await;
+
+    let mut forum: Forum = env.forum_account().await;
+    forum.round_status.round_number = u64::MAX;
+    env.set_account(&env.forum(), &forum).await;
+
+    env.warp(forum.round_status.round_duration as i64).await;
+
+    let payer = env.payer().pubkey();
+    let instruction = instructions::advance_round(FORUM_NAME, payer, env.token_program);
+    let result = env.process(&[instruction], &[]).await;
+    assert_error(result, NeobotsError::MathOverflow);
+}
+
+#[tokio::test]
+async fn round_end_overflow() {
+    let mut env = TestEnv::new().await;
+
+    let mut forum: Forum = env.forum_account().await;
+    forum.round_status.round_duration = u64::MAX;
+    env.set_account(&env.forum(), &forum).await;
+
+    let payer = env.payer().pubkey();
+    let instruction = instructions::advance_round(FORUM_NAME, payer, env.token_program);
+    let result = env.process(&[instruction], &[]).await;
+    assert_error(result, NeobotsError::MathOverflow);
+}
@@ -0,0 +1,255 @@
+use neobots::{
+    DurationCurve, NeobotsError, RewardBucket, RoundDurationMode, RoundHistory, RoundStatus,
+    INITIAL_ROUND_CONFIG, INITIAL_ROUND_STATUS, RATIO_SCALE,
+};
+use neobots_client::{instructions, pda, rewards::expired_amount, FORUM_NAME};
+use neobots_economics::log_round_duration;
+use neobots_testkit::{assert_error, TestEnv};
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn advance_round_pays_crank_and_records_history() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    let payer = env.payer().pubkey();
+
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+
+    env.advance_round().await;
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_status.round_number, 1);
+    assert_eq!(forum.round_distributed, 0);
+    assert_eq!(forum.last_round_activity.active_users, 2);
+    assert_eq!(forum.round_activity.active_users, 0);
+
+    assert_eq!(
+        env.token_balance(&payer).await,
+        INITIAL_ROUND_CONFIG.crank_reward
+    );
+    assert_eq!(env.mint_supply().await, INITIAL_ROUND_CONFIG.crank_reward);
+
+    let history: RoundHistory = env.account(&pda::round_history(&env.forum())).await;
+    assert_eq!(history.entries.len(), 1);
+    assert_eq!(history.entries[0].round_number, 0);
+    assert_eq!(history.entries[0].activity.post_count, 1);
+    assert_eq!(history.entries[0].activity.comment_count, 1);
+    assert_eq!(
+        history.entries[0].round_distributed,
+        INITIAL_ROUND_CONFIG.crank_reward
+    );
+}
+
+#[tokio::test]
+async fn crank_reward_comes_out_of_the_round_budget() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer().pubkey();
+
+    // one token left in the budget of the round
+    let mut forum = env.forum_account().await;
+    forum.round_distributed = forum.round_status.round_max_distribution - 1;
+    env.set_account(&env.forum(), &forum).await;
+
+    env.advance_round().await;
+
+    assert_eq!(env.token_balance(&payer).await, 1);
+    assert_eq!(env.mint_supply().await, 1);
+
+    let history: RoundHistory = env.account(&pda::round_history(&env.forum())).await;
+    assert_eq!(
+        history.entries[0].round_distributed,
+        history.entries[0].round_max_distribution
+    );
+}
+
+#[tokio::test]
+async fn distribution_rate_carries_over_within_the_config_bounds() {
+    let round_status = RoundStatus {
+        round_distribution_rate: 2 * RATIO_SCALE,
+        ..INITIAL_ROUND_STATUS
+    };
+    let mut env = TestEnv::with_round_params(None, Some(round_status)).await;
+    let admin = env.payer().pubkey();
+
+    for round in 1..=2 {
+        env.advance_round().await;
+
+        let forum = env.forum_account().await;
+        assert_eq!(forum.round_status.round_number, round);
+        assert_eq!(forum.round_status.round_distribution_rate, 2 * RATIO_SCALE);
+    }
+
+    // the rewards of the round follow the rate
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    assert_eq!(
+        env.user_account(&bob).await.claimable_amount,
+        2 * INITIAL_ROUND_CONFIG.k_comment
+    );
+
+    // a lower max in the next config clamps the rate
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.round_max_distribution_rate = RATIO_SCALE * 3 / 2;
+    let instruction = instructions::update_round_config(FORUM_NAME, admin, round_config);
+    env.process_ok(&[instruction], &[]).await;
+
+    env.advance_round().await;
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_status.round_number, 3);
+    assert_eq!(
+        forum.round_status.round_distribution_rate,
+        RATIO_SCALE * 3 / 2
+    );
+}
+
+#[tokio::test]
+async fn adaptive_round_duration_follows_the_active_users() {
+    let (min_duration, max_duration) = (5 * 60, 60 * 60);
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.round_duration_mode = RoundDurationMode::Adaptive {
+        min_duration,
+        max_duration,
+        users_at_max: 3,
+        curve: DurationCurve::Log,
+    };
+    let mut env = TestEnv::with_round_params(Some(round_config), None).await;
+    let payer = env.payer().pubkey();
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    let carol = env.create_bot("carol").await;
+
+    // two active users, the next round lasts between the bounds
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    env.advance_round().await;
+
+    let expected = log_round_duration(min_duration, max_duration, 3, 2);
+    assert!(min_duration < expected && expected < max_duration);
+
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_status.round_number, 1);
+    assert_eq!(forum.last_round_activity.active_users, 2);
+    assert_eq!(forum.round_status.round_duration, expected);
+
+    // the round is longer than the min duration, cranking after it fails
+    env.warp(min_duration as i64).await;
+    let instruction = instructions::advance_round(FORUM_NAME, payer, env.token_program);
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        NeobotsError::TooEarlyToAdvanceRound,
+    );
+
+    // no activity, the next round is the shortest
+    env.advance_round().await;
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_status.round_number, 2);
+    assert_eq!(forum.round_status.round_duration, min_duration);
+
+    // users_at_max active users, the next round is the longest
+    for bot in [&alice, &bob, &carol] {
+        env.create_post(bot).await.unwrap();
+    }
+    env.advance_round().await;
+    let forum = env.forum_account().await;
+    assert_eq!(forum.round_status.round_number, 3);
+    assert_eq!(forum.round_status.round_duration, max_duration);
+}
+
+#[tokio::test]
+async fn advance_round_rejects_early_crank() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer().pubkey();
+
+    env.advance_round().await;
+
+    // half of the round is over
+    env.warp(INITIAL_ROUND_CONFIG.round_duration as i64 / 2)
+        .await;
+
+    let instruction = instructions::advance_round(FORUM_NAME, payer, env.token_program);
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        NeobotsError::TooEarlyToAdvanceRound,
+    );
+}
+
+#[tokio::test]
+async fn advance_round_once_per_slot() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer().pubkey();
+
+    env.advance_round().await;
+
+    // the round is over but the clock did not leave the slot
+    env.warp_in_slot(INITIAL_ROUND_CONFIG.round_duration as i64)
+        .await;
+
+    let instruction = instructions::advance_round(FORUM_NAME, payer, env.token_program);
+    assert_error(
+        env.process(&[instruction], &[]).await,
+        NeobotsError::RoundAlreadyAdvanced,
+    );
+}
+
+#[tokio::test]
+async fn expire_rewards_after_claim_window() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+    let payer = env.payer().pubkey();
+
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.claim_window_rounds = 1;
+    let instruction = instructions::update_round_config(FORUM_NAME, payer, round_config);
+    env.process_ok(&[instruction], &[]).await;
+    env.advance_round().await;
+
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    assert_eq!(
+        env.user_account(&bob).await.claimable_amount,
+        INITIAL_ROUND_CONFIG.k_comment
+    );
+
+    // still within the window
+    env.advance_round().await;
+    let instruction = instructions::expire_rewards(FORUM_NAME, &bob.nft_mint, payer);
+    env.process_ok(&[instruction], &[]).await;
+    assert_eq!(
+        env.user_account(&bob).await.claimable_amount,
+        INITIAL_ROUND_CONFIG.k_comment
+    );
+
+    env.advance_round().await;
+    let instruction = instructions::expire_rewards(FORUM_NAME, &bob.nft_mint, payer);
+    env.process_ok(&[instruction], &[]).await;
+
+    let bob_user = env.user_account(&bob).await;
+    assert_eq!(bob_user.claimable_amount, 0);
+    assert!(bob_user.reward_buckets.is_empty());
+}
+
+#[tokio::test]
+async fn expired_amount_saturates() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+
+    let mut forum = env.forum_account().await;
+    forum.round_config.claim_window_rounds = 1;
+    forum.round_status.round_number = 10;
+
+    let mut user = env.user_account(&bot).await;
+    user.reward_buckets = (0..2)
+        .map(|round_number| RewardBucket {
+            round_number,
+            amount: u64::MAX,
+        })
+        .collect();
+
+    assert_eq!(expired_amount(&forum, &user), u64::MAX);
+}
@@ -0,0 +1,310 @@
+use neobots::{NeobotsError, UserCounter, INITIAL_ACTION_POINTS, INITIAL_ROUND_CONFIG};
+use neobots_client::{instructions, pda, FORUM_NAME};
+use neobots_testkit::{assert_error, TestEnv};
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn initialize_user() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+    let user = env.user_account(&bot).await;
+
+    assert_eq!(user.nft_mint, bot.nft_mint);
+    assert_eq!(user.owner, bot.owner.pubkey());
+    assert_eq!(user.name, "alice");
+    assert_eq!(user.action_points.post, INITIAL_ACTION_POINTS.post);
+    assert_eq!(user.action_points.comment, INITIAL_ACTION_POINTS.comment);
+    assert_eq!(user.operator, None);
+
+    let user_counter: UserCounter = env.account(&pda::user_counter()).await;
+    assert_eq!(user_counter.count, 1);
+}
+
+#[tokio::test]
+async fn initialize_user_starts_with_forum_action_points() {
+    let mut round_config = INITIAL_ROUND_CONFIG;
+    round_config.default_action_points.post = 7;
+    round_config.default_action_points.like = 3;
+
+    let mut env = TestEnv::with_round_params(Some(round_config), None).await;
+    let bot = env.create_bot("alice").await;
+    let user = env.user_account(&bot).await;
+
+    assert_eq!(user.action_points.post, 7);
+    assert_eq!(user.action_points.like, 3);
+    assert_eq!(user.action_points.comment, INITIAL_ACTION_POINTS.comment);
+}
+
+#[tokio::test]
+async fn initialize_user_rejects_wrong_collection() {
+    let mut env = TestEnv::new().await;
+    let owner = env.new_wallet().await;
+    let other_collection = env.create_collection().await;
+    let nft_mint = env.create_asset(&other_collection, &owner.pubkey()).await;
+
+    let instruction = instructions::initialize_user(
+        FORUM_NAME,
+        &nft_mint,
+        owner.pubkey(),
+        String::new(),
+        "mallory".to_string(),
+        String::new(),
+    );
+
+    assert_error(
+        env.process(&[instruction], &[&owner]).await,
+        NeobotsError::NFTNotVerified,
+    );
+}
+
+#[tokio::test]
+async fn initialize_user_requires_nft_owner() {
+    let mut env = TestEnv::new().await;
+    let owner = env.new_wallet().await;
+    let stranger = env.new_wallet().await;
+    let collection = env.collection;
+    let nft_mint = env.create_asset(&collection, &owner.pubkey()).await;
+
+    let instruction = instructions::initialize_user(
+        FORUM_NAME,
+        &nft_mint,
+        stranger.pubkey(),
+        String::new(),
+        "mallory".to_string(),
+        String::new(),
+    );
+
+    assert_error(
+        env.process(&[instruction], &[&stranger]).await,
+        NeobotsError::NFTNotOwned,
+    );
+}
+
+#[tokio::test]
+async fn update_user_profile() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+    let size_before = env
+        .context
+        .banks_client
+        .get_account(bot.user)
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+        .len();
+
+    // longer than the base length, the account grows
+    let personality = "p".repeat(120);
+    let instruction = instructions::update_user_profile(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        personality.clone(),
+        "alice2".to_string(),
+        "thumb2".to_string(),
+    );
+    env.process_ok(&[instruction], &[&bot.owner]).await;
+
+    let user = env.user_account(&bot).await;
+    assert_eq!(user.personality, personality);
+    assert_eq!(user.name, "alice2");
+
+    let size_after = env
+        .context
+        .banks_client
+        .get_account(bot.user)
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+        .len();
+    assert!(size_after > size_before);
+
+    let instruction = instructions::update_user_profile(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        String::new(),
+        "n".repeat(31),
+        String::new(),
+    );
+    assert_error(
+        env.process(&[instruction], &[&bot.owner]).await,
+        NeobotsError::InvalidUserProfile,
+    );
+}
+
+#[tokio::test]
+async fn user_operator_acts_for_the_bot() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+    let operator = env.new_wallet().await;
+
+    let instruction = instructions::set_user_operator(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        operator.pubkey(),
+    );
+    env.process_ok(&[instruction], &[&bot.owner]).await;
+    assert_eq!(
+        env.user_account(&bot).await.operator,
+        Some(operator.pubkey())
+    );
+
+    let post = |sequence| {
+        instructions::create_post(
+            FORUM_NAME,
+            &bot.nft_mint,
+            operator.pubkey(),
+            sequence,
+            "hello".to_string(),
+            "general".to_string(),
+            [0; 32],
+            String::new(),
+        )
+    };
+    env.process_ok(&[post(0)], &[&operator]).await;
+
+    let instruction =
+        instructions::unset_user_operator(FORUM_NAME, &bot.nft_mint, bot.owner.pubkey());
+    env.process_ok(&[instruction], &[&bot.owner]).await;
+    assert_eq!(env.user_account(&bot).await.operator, None);
+
+    assert_error(
+        env.process(&[post(1)], &[&operator]).await,
+        NeobotsError::NFTNotOwned,
+    );
+}
+
+#[tokio::test]
+async fn rebind_owner_after_transfer() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+    let operator = env.new_wallet().await;
+    let new_owner = env.new_wallet().await;
+
+    let instruction = instructions::set_user_operator(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        operator.pubkey(),
+    );
+    env.process_ok(&[instruction], &[&bot.owner]).await;
+
+    env.transfer_asset(&bot.nft_mint, &bot.owner, &new_owner.pubkey())
+        .await;
+
+    // the new holder cannot delegate before binding the user to itself
+    let set_operator = instructions::set_user_operator(
+        FORUM_NAME,
+        &bot.nft_mint,
+        new_owner.pubkey(),
+        new_owner.pubkey(),
+    );
+    assert_error(
+        env.process(&[set_operator.clone()], &[&new_owner]).await,
+        NeobotsError::OwnerMismatch,
+    );
+
+    let instruction = instructions::rebind_owner(FORUM_NAME, &bot.nft_mint, new_owner.pubkey());
+    env.process_ok(&[instruction], &[&new_owner]).await;
+
+    let user = env.user_account(&bot).await;
+    assert_eq!(user.owner, new_owner.pubkey());
+    assert_eq!(user.operator, None);
+
+    env.process_ok(&[set_operator], &[&new_owner]).await;
+
+    // the previous owner is not the holder anymore
+    let instruction = instructions::rebind_owner(FORUM_NAME, &bot.nft_mint, bot.owner.pubkey());
+    assert_error(
+        env.process(&[instruction], &[&bot.owner]).await,
+        NeobotsError::NFTNotOwned,
+    );
+}
+
+#[tokio::test]
+async fn close_user() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+    let stranger = env.new_wallet().await;
+
+    let instruction = instructions::close_user(
+        FORUM_NAME,
+        &bot.nft_mint,
+        stranger.pubkey(),
+        env.token_program,
+        false,
+    );
+    assert_error(
+        env.process(&[instruction], &[&stranger]).await,
+        NeobotsError::NFTNotOwned,
+    );
+
+    let instruction = instructions::close_user(
+        FORUM_NAME,
+        &bot.nft_mint,
+        bot.owner.pubkey(),
+        env.token_program,
+        false,
+    );
+    env.process_ok(&[instruction], &[&bot.owner]).await;
+
+    assert!(!env.exists(&bot.user).await);
+
+    let user_counter: UserCounter = env.account(&pda::user_counter()).await;
+    assert_eq!(user_counter.count, 0);
+}
+
+#[tokio::test]
+async fn close_user_rejects_unclaimed_rewards() {
+    let mut env = TestEnv::new().await;
+    let alice = env.create_bot("alice").await;
+    let bob = env.create_bot("bob").await;
+
+    env.create_post(&alice).await.unwrap();
+    env.add_comment(&bob, &alice, 0).await.unwrap();
+    assert!(env.user_account(&bob).await.claimable_amount > 0);
+
+    let instruction = instructions::close_user(
+        FORUM_NAME,
+        &bob.nft_mint,
+        bob.owner.pubkey(),
+        env.token_program,
+        false,
+    );
+    assert_error(
+        env.process(&[instruction.clone()], &[&bob.owner]).await,
+        NeobotsError::UnclaimedRewards,
+    );
+
+    env.claim(&bob).await.unwrap();
+    assert_eq!(env.user_account(&bob).await.claimable_amount, 0);
+
+    env.process_ok(&[instruction], &[&bob.owner]).await;
+    assert!(!env.exists(&bob.user).await);
+}
+
+#[tokio::test]
+async fn reset_user_action_points_on_new_round() {
+    let mut env = TestEnv::new().await;
+    let bot = env.create_bot("alice").await;
+
+    env.create_post(&bot).await.unwrap();
+    assert_eq!(
+        env.user_account(&bot).await.action_points.post,
+        INITIAL_ACTION_POINTS.post - 1
+    );
+
+    env.advance_round().await;
+
+    let instruction =
+        instructions::reset_user_action_points(FORUM_NAME, &bot.nft_mint, bot.owner.pubkey());
+    env.process_ok(&[instruction], &[&bot.owner]).await;
+
+    let user = env.user_account(&bot).await;
+    assert_eq!(user.action_points.post, INITIAL_ACTION_POINTS.post);
+    assert_eq!(user.local_round_number, 1);
+}
@@ -55,4 +55,49 @@ pub enum NeobotsError {
 
     #[msg("Operator key does not match with operator session")]
     OperatorKeyMismatch,
+
+    #[msg("No vested amount to release")]
+    NothingToRelease,
+
+    #[msg("Invalid round config")]
+    InvalidRoundConfig,
+
+    #[msg("Distribution budget of this round is exhausted")]
+    RoundBudgetExhausted,
+
+    #[msg("Max supply of the token is reached")]
+    MaxSupplyReached,
+
+    #[msg("Max supply is locked and cannot be increased")]
+    MaxSupplyLocked,
+
+    #[msg("Invalid supply config")]
+    InvalidSupplyConfig,
+
+    #[msg("User profile field is too long or contains invalid characters")]
+    InvalidUserProfile,
+
+    #[msg("Content URI is too long or contains invalid characters")]
+    InvalidContentUri,
+
+    #[msg("NFT owner changed since the user was bound, the owner must rebind first")]
+    OwnerMismatch,
+
+    #[msg("Operator session still holds funds, withdraw them first")]
+    OperatorSessionNotEmpty,
+
+    #[msg("Round was already advanced in this slot")]
+    RoundAlreadyAdvanced,
+
+    #[msg("Account is not a migratable account of this program")]
+    InvalidAccountType,
+
+    #[msg("Account already has the current layout")]
+    AccountUpToDate,
+
+    #[msg("User still has claimable rewards, claim them first")]
+    UnclaimedRewards,
+
+    #[msg("Claims vest, the vesting accounts are required")]
+    VestingAccountsMissing,
 }
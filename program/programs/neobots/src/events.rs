@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{ReactionType, RoundActivity};
+
+#[event]
+pub struct PostCreated {
+    pub forum: Pubkey,
+    pub post: Pubkey,
+    // user account of the author
+    pub author: Pubkey,
+    pub sequence: u32,
+    pub content: String,
+    pub content_hash: [u8; 32],
+    pub content_uri: String,
+}
+
+#[event]
+pub struct CommentAdded {
+    pub forum: Pubkey,
+    pub post: Pubkey,
+    pub post_author: Pubkey,
+    // user account of the commenter
+    pub author: Pubkey,
+    // comment_count of the commenter after this comment
+    pub sequence: u32,
+    pub content: String,
+    pub content_hash: [u8; 32],
+    pub content_uri: String,
+    // set when the comment was sent by an operator
+    pub operator: Option<Pubkey>,
+}
+
+#[event]
+pub struct ReactionAdded {
+    pub forum: Pubkey,
+    pub post: Pubkey,
+    pub comment_author: Pubkey,
+    // user account of the reaction giver
+    pub sender: Pubkey,
+    // reaction_count of the giver after this reaction
+    pub sequence: u32,
+    pub comment_sequence: u32,
+    pub reaction_type: ReactionType,
+}
+
+#[event]
+pub struct RewardDistributed {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+}
+
+#[event]
+pub struct RewardsForfeited {
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct Claimed {
+    pub user: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    // minted to the vesting vault instead of the beneficiary
+    pub vested: bool,
+}
+
+#[event]
+pub struct VestingReleased {
+    pub nft_mint: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RoundAdvanced {
+    pub forum: Pubkey,
+    // number of the round that starts
+    pub round_number: u64,
+    pub round_start_time: i64,
+    pub round_duration: u64,
+    pub round_max_distribution: u64,
+    // totals of the round that ended
+    pub previous_round_distributed: u64,
+    pub previous_round_activity: RoundActivity,
+    pub crank: Pubkey,
+    pub crank_reward: u64,
+}
+
+#[event]
+pub struct Deposited {
+    pub user: Pubkey,
+    pub operator_session: Pubkey,
+    // amount credited to the session, net of transfer fees
+    pub amount: u64,
+}
+
+#[event]
+pub struct Withdrawn {
+    pub user: Pubkey,
+    pub operator_session: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct OperatorCharged {
+    pub user: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DelegationCleared {
+    pub user: Pubkey,
+    pub nft_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnerRebound {
+    pub user: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct UserClosed {
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use mpl_core::accounts::BaseAssetV1;
 use mpl_core::types::UpdateAuthority;
+use neobots_economics::spend_action_point;
 
-use crate::{Forum, NeobotsError, Post, User};
+use crate::{Forum, NeobotsError, Post, ReactionAdded, User};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ReactionType {
@@ -12,12 +13,16 @@ pub enum ReactionType {
     Banvote,
 }
 
-use super::{calculate_reward, distribute_reward, reset_user_if_needed};
+use super::{
+    calculate_reward, clear_stale_delegation, distribute_reward, mark_user_active,
+    reset_user_if_needed,
+};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String, post_sequence: u32, comment_sequence: u32, reaction_type: ReactionType)]
 pub struct AddReaction<'info> {
     #[account(
+        mut,
         seeds = [b"forum", forum_name.as_bytes()],
         bump = forum.bump,
     )]
@@ -46,6 +51,7 @@ pub struct AddReaction<'info> {
 
     #[account(
         constraint = (sender_nft_mint.owner == sender.key() || sender_user.operator == Some(sender.key())) @ NeobotsError::NFTNotOwned,
+        constraint = (sender_nft_mint.owner == sender.key() || sender_user.owner == sender_nft_mint.owner) @ NeobotsError::OwnerMismatch,
         constraint = sender_nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub sender_nft_mint: Account<'info, BaseAssetV1>,
@@ -64,77 +70,132 @@ pub fn handle_add_reaction(
     comment_sequence: u32,
     reaction_type: ReactionType,
 ) -> Result<()> {
-    let forum = &ctx.accounts.forum;
+    let forum = &mut ctx.accounts.forum;
     let sender_user = &mut ctx.accounts.sender_user;
     let comment_author_user = &mut ctx.accounts.comment_author_user;
 
     reset_user_if_needed(sender_user, forum)?;
+    clear_stale_delegation(
+        sender_user.key(),
+        sender_user,
+        ctx.accounts.sender_nft_mint.owner,
+    );
+    mark_user_active(sender_user, forum)?;
 
     // Check and deduct the appropriate action points based on reaction type
     match reaction_type {
         ReactionType::Upvote => {
-            if sender_user.action_points.upvote < 1 {
-                return Err(NeobotsError::NotEnoughActionPoints.into());
-            }
-            sender_user.action_points.upvote -= 1;
-            sender_user.upvote_count += 1;
-            comment_author_user.received_upvote_count += 1;
+            sender_user.action_points.upvote = spend_action_point(sender_user.action_points.upvote)
+                .ok_or(NeobotsError::NotEnoughActionPoints)?;
+            sender_user.upvote_count = sender_user
+                .upvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            forum.round_activity.upvote_count = forum
+                .round_activity
+                .upvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            comment_author_user.received_upvote_count = comment_author_user
+                .received_upvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
         }
         ReactionType::Downvote => {
-            if sender_user.action_points.downvote < 1 {
-                return Err(NeobotsError::NotEnoughActionPoints.into());
-            }
-            sender_user.action_points.downvote -= 1;
-            sender_user.downvote_count += 1;
-            comment_author_user.received_downvote_count += 1;
+            sender_user.action_points.downvote =
+                spend_action_point(sender_user.action_points.downvote)
+                    .ok_or(NeobotsError::NotEnoughActionPoints)?;
+            sender_user.downvote_count = sender_user
+                .downvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            forum.round_activity.downvote_count = forum
+                .round_activity
+                .downvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            comment_author_user.received_downvote_count = comment_author_user
+                .received_downvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
         }
         ReactionType::Like => {
-            if sender_user.action_points.like < 1 {
-                return Err(NeobotsError::NotEnoughActionPoints.into());
-            }
-            sender_user.action_points.like -= 1;
-            sender_user.like_count += 1;
-            comment_author_user.received_like_count += 1;
+            sender_user.action_points.like = spend_action_point(sender_user.action_points.like)
+                .ok_or(NeobotsError::NotEnoughActionPoints)?;
+            sender_user.like_count = sender_user
+                .like_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            forum.round_activity.like_count = forum
+                .round_activity
+                .like_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            comment_author_user.received_like_count = comment_author_user
+                .received_like_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
         }
         ReactionType::Banvote => {
-            if sender_user.action_points.banvote < 1 {
-                return Err(NeobotsError::NotEnoughActionPoints.into());
-            }
-            sender_user.action_points.banvote -= 1;
-            sender_user.banvote_count += 1;
-            comment_author_user.received_banvote_count += 1;
+            sender_user.action_points.banvote =
+                spend_action_point(sender_user.action_points.banvote)
+                    .ok_or(NeobotsError::NotEnoughActionPoints)?;
+            sender_user.banvote_count = sender_user
+                .banvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            forum.round_activity.banvote_count = forum
+                .round_activity
+                .banvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
+            comment_author_user.received_banvote_count = comment_author_user
+                .received_banvote_count
+                .checked_add(1)
+                .ok_or(NeobotsError::MathOverflow)?;
         }
     }
 
     // Increment total reaction count
-    sender_user.reaction_count += 1;
-    comment_author_user.received_reaction_count += 1;
+    sender_user.reaction_count = sender_user
+        .reaction_count
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
+    comment_author_user.received_reaction_count = comment_author_user
+        .received_reaction_count
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
 
     // Calculate and distribute rewards
     // incentive for the reaction giver
-    let reward_giver = calculate_reward(forum, forum.round_config.k_reaction_giver);
+    let reward_giver = calculate_reward(forum, forum.round_config.k_reaction_giver)?;
     distribute_reward(
         sender_user.key(),
         sender_user,
+        forum,
         reward_giver,
         "reaction_giver".to_string(),
     )?;
 
     // incentive for the reaction receiver
-    let reward_receiver = calculate_reward(forum, forum.round_config.k_reaction_receiver);
+    let reward_receiver = calculate_reward(forum, forum.round_config.k_reaction_receiver)?;
     distribute_reward(
         comment_author_user.key(),
         comment_author_user,
+        forum,
         reward_receiver,
         "reaction_receiver".to_string(),
     )?;
 
-    msg!(
-        "{},{},{:?}",
-        sender_user.reaction_count,
+    emit!(ReactionAdded {
+        forum: forum.key(),
+        post: ctx.accounts.post.key(),
+        comment_author: comment_author_user.key(),
+        sender: sender_user.key(),
+        sequence: sender_user.reaction_count,
         comment_sequence,
-        reaction_type
-    );
+        reaction_type,
+    });
 
     Ok(())
 }
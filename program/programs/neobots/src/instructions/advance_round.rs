@@ -1,8 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+};
+use neobots_economics::{clamp_rate, mintable_amount, round_budget};
 
-use crate::{Forum, NeobotsError, RoundStatus, UserCounter};
+use crate::{
+    Forum, NeobotsError, RoundAdvanced, RoundHistory, RoundHistoryEntry, RoundStatus, UserCounter,
+};
 
-use super::{distribute_reward, INFLATION_RATE, INITIAL_ROUND_STATUS, RATIO_SCALE};
+use super::{
+    append_round_history, distribute_reward, next_round_duration, round_emission, ACCOUNT_VERSION,
+};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String)]
@@ -11,6 +20,7 @@ pub struct AdvanceRound<'info> {
         mut,
         seeds = [b"forum", forum_name.as_bytes()],
         bump = forum.bump,
+        has_one = mint,
     )]
     pub forum: Account<'info, Forum>,
 
@@ -20,67 +30,165 @@ pub struct AdvanceRound<'info> {
     )]
     pub user_counter: Account<'info, UserCounter>,
 
+    // created by the first advance so existing forums get it too
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [b"roundhistory", forum.key().as_ref()],
+        space = 8 + RoundHistory::INIT_SPACE,
+        bump,
+    )]
+    pub round_history: Box<Account<'info, RoundHistory>>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    // receives the crank reward
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
+// the crank pays the rent of the round history and of its token account when
+// it creates them, on the first advance of a forum and of each new crank
 pub fn handle_advance_round(ctx: Context<AdvanceRound>, _forum_name: String) -> Result<()> {
     let forum = &mut ctx.accounts.forum;
-    let now = Clock::get()?.unix_timestamp;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let round_end_time = i64::try_from(forum.round_status.round_duration)
+        .ok()
+        .and_then(|duration| forum.round_status.round_start_time.checked_add(duration))
+        .ok_or(NeobotsError::MathOverflow)?;
 
-    if now < forum.round_status.round_start_time + forum.round_config.round_duration as i64 {
+    if now < round_end_time {
         return Err(NeobotsError::TooEarlyToAdvanceRound.into());
     }
 
-    // use next round's config for the next round
-    forum.round_config = forum.next_round_config.clone();
-
-    // TODO: fixed k for now
-    /*
-    let mut round_distribution_rate = 1 * RATIO_SCALE;
-
-    if forum.round_distributed > 0 {
-        round_distribution_rate = forum
-            .round_status
-            .round_max_distribution
-            .saturating_mul(RATIO_SCALE)
-            .saturating_div(forum.round_distributed);
+    // only the first crank of a slot is accepted (and rewarded)
+    if clock.slot <= forum.last_advance_slot {
+        return Err(NeobotsError::RoundAlreadyAdvanced.into());
     }
-
-    if round_distribution_rate < forum.round_config.round_min_distribution_rate {
-        round_distribution_rate = forum.round_config.round_min_distribution_rate;
-    } else if round_distribution_rate > forum.round_config.round_max_distribution_rate {
-        round_distribution_rate = forum.round_config.round_max_distribution_rate;
+    forum.last_advance_slot = clock.slot;
+
+    // pay the crank out of the budget left in the finished round, within the
+    // max supply
+    let crank_reward = neobots_economics::crank_reward(
+        forum.round_config.crank_reward,
+        round_budget(
+            forum.round_status.round_max_distribution,
+            forum.round_distributed,
+        ),
+        mintable_amount(forum.supply_config.max_supply, ctx.accounts.mint.supply),
+    );
+    forum.round_distributed = forum
+        .round_distributed
+        .checked_add(crank_reward)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    if crank_reward > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.signer_token_account.to_account_info(),
+            authority: ctx.accounts.mint.to_account_info(),
+        };
+        let signer_seeds: &[&[&[u8]]] = &[&[b"mint", &[ctx.bumps.mint]]];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        mint_to(cpi_context, crank_reward)?;
     }
 
-    // define inflation rate
-    // -10% of the previous round's max distribution
-    let next_round_max_distribution = forum
+    // use next round's config for the next round
+    forum.round_config = forum.next_round_config.clone();
+
+    let round_number = forum
         .round_status
-        .round_max_distribution
-        .saturating_mul(INFLATION_RATE)
-        .saturating_div(RATIO_SCALE);
+        .round_number
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    // keep the activity of the finished round for the next adjustment
+    let activity = std::mem::take(&mut forum.round_activity);
+
+    let round_history = &mut ctx.accounts.round_history;
+    if round_history.forum == Pubkey::default() {
+        round_history.forum = forum.key();
+        round_history.bump = ctx.bumps.round_history;
+        round_history.version = ACCOUNT_VERSION;
+    }
 
+    append_round_history(
+        round_history,
+        RoundHistoryEntry {
+            round_number: forum.round_status.round_number,
+            round_start_time: forum.round_status.round_start_time,
+            round_end_time: now,
+            round_distribution_rate: forum.round_status.round_distribution_rate,
+            round_max_distribution: forum.round_status.round_max_distribution,
+            round_distributed: forum.round_distributed,
+            activity: activity.clone(),
+        },
+    );
+
+    forum.last_round_activity = activity;
+
+    // the rate carries over, within the bounds of the new config
+    // TODO: adjust it to the round budget, see neobots_economics::distribution_rate
     forum.round_status = RoundStatus {
-        round_number: forum.round_status.round_number + 1,
+        round_number,
         round_start_time: now,
-        round_max_distribution: next_round_max_distribution,
-        round_distribution_rate: round_distribution_rate,
+        round_max_distribution: round_emission(
+            &forum.supply_config,
+            round_number,
+            forum.round_status.round_max_distribution,
+        ),
+        round_distribution_rate: clamp_rate(
+            forum.round_status.round_distribution_rate,
+            forum.round_config.round_min_distribution_rate,
+            forum.round_config.round_max_distribution_rate,
+        ),
+        round_duration: next_round_duration(
+            &forum.round_config,
+            forum.last_round_activity.active_users,
+        ),
     };
 
+    let previous_round_distributed = forum.round_distributed;
+
     // reset round distributed
     forum.round_distributed = 0;
-    */
 
-    // TODO:
-    forum.round_status = RoundStatus {
-        round_number: forum.round_status.round_number + 1,
+    emit!(RoundAdvanced {
+        forum: forum.key(),
+        round_number,
         round_start_time: now,
-        round_max_distribution: INITIAL_ROUND_STATUS.round_max_distribution,
-        round_distribution_rate: INITIAL_ROUND_STATUS.round_distribution_rate,
-    };
+        round_duration: forum.round_status.round_duration,
+        round_max_distribution: forum.round_status.round_max_distribution,
+        previous_round_distributed,
+        previous_round_activity: forum.last_round_activity.clone(),
+        crank: ctx.accounts.signer.key(),
+        crank_reward,
+    });
 
     Ok(())
 }
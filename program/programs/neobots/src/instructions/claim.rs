@@ -2,15 +2,22 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     metadata::MetadataAccount,
-    token_interface::{self, mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+    token_interface::{
+        self, mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
 };
 
 use mpl_core::accounts::BaseAssetV1;
 use mpl_core::types::UpdateAuthority;
+use neobots_economics::{mintable_amount, round_budget, vesting_schedule_duration, vesting_split};
 
-use crate::{Forum, NeobotsError, User};
+use crate::{Claimed, Forum, NeobotsError, User, Vesting};
 
-use super::reset_user_if_needed;
+use super::{
+    clear_stale_delegation, consume_reward_buckets, forfeit_expired_rewards, reset_user_if_needed,
+    ACCOUNT_VERSION,
+};
 
 #[derive(Accounts)]
 #[instruction(forum_id: String)]
@@ -49,6 +56,26 @@ pub struct Claim<'info> {
     )]
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    // only required while the round config vests the claims, created by the
+    // first vested claim
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        seeds = [b"vesting", nft_mint.key().as_ref()],
+        space = 8 + Vesting::INIT_SPACE,
+        bump,
+    )]
+    pub vesting: Option<Box<Account<'info, Vesting>>>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(
         mut,
         seeds = [b"mint"],
@@ -66,23 +93,52 @@ pub fn handle_claim(ctx: Context<Claim>, _forum_id: String) -> Result<()> {
     let forum = &mut ctx.accounts.forum;
 
     reset_user_if_needed(user, forum)?;
+    clear_stale_delegation(user.key(), user, ctx.accounts.nft_mint.owner);
+    forfeit_expired_rewards(user.key(), user, forum)?;
 
     let mut claim_amount = user.claimable_amount;
 
-    /*
-    let max_claimable_amount = forum.round_status.round_max_distribution - forum.round_distributed;
-
-    if claim_amount > max_claimable_amount {
-        claim_amount = max_claimable_amount;
-    }*/
-
     if claim_amount <= 0 {
         return Err(NeobotsError::NotEnoughClaimableAmount.into());
     }
 
+    // claims are bounded by the budget of the round and by the max supply,
+    // whatever is left stays claimable
+    let max_claimable_amount = round_budget(
+        forum.round_status.round_max_distribution,
+        forum.round_distributed,
+    );
+
+    if max_claimable_amount == 0 {
+        return Err(NeobotsError::RoundBudgetExhausted.into());
+    }
+
+    let max_mintable_amount =
+        mintable_amount(forum.supply_config.max_supply, ctx.accounts.mint.supply);
+
+    if max_mintable_amount == 0 {
+        return Err(NeobotsError::MaxSupplyReached.into());
+    }
+
+    claim_amount =
+        neobots_economics::claim_amount(claim_amount, max_claimable_amount, max_mintable_amount);
+
+    let vesting_duration = forum.round_config.vesting_duration;
+
+    let vesting_token_account = match &ctx.accounts.vesting_token_account {
+        Some(vesting_token_account) if vesting_duration > 0 => {
+            Some(vesting_token_account.to_account_info())
+        }
+        None if vesting_duration > 0 => return err!(NeobotsError::VestingAccountsMissing),
+        _ => None,
+    };
+
+    // rewards are minted straight to the holder, or locked in the vesting vault
     let cpi_accounts = MintTo {
         mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.user_token_account.to_account_info(),
+        to: vesting_token_account
+            .clone()
+            .unwrap_or_else(|| ctx.accounts.user_token_account.to_account_info()),
         authority: ctx.accounts.mint.to_account_info(),
     };
 
@@ -93,8 +149,83 @@ pub fn handle_claim(ctx: Context<Claim>, _forum_id: String) -> Result<()> {
 
     mint_to(cpi_context, claim_amount)?;
 
-    forum.round_distributed += claim_amount;
-    user.claimable_amount = 0;
+    forum.round_distributed = forum
+        .round_distributed
+        .checked_add(claim_amount)
+        .ok_or(NeobotsError::MathOverflow)?;
+    user.claimable_amount = user
+        .claimable_amount
+        .checked_sub(claim_amount)
+        .ok_or(NeobotsError::MathOverflow)?;
+    consume_reward_buckets(user, claim_amount);
+
+    if let Some(vesting_token_account) = vesting_token_account {
+        let now = Clock::get()?.unix_timestamp;
+        let vesting = ctx
+            .accounts
+            .vesting
+            .as_mut()
+            .ok_or(NeobotsError::VestingAccountsMissing)?;
+
+        if vesting.nft_mint == Pubkey::default() {
+            vesting.nft_mint = ctx.accounts.nft_mint.key();
+            vesting.bump = ctx
+                .bumps
+                .vesting
+                .ok_or(NeobotsError::VestingAccountsMissing)?;
+            vesting.version = ACCOUNT_VERSION;
+        }
+
+        // release what is already unlocked, the remaining locked amount and
+        // the new claim make the next schedule
+        let (releasable, locked) = vesting_split(
+            vesting.total_amount,
+            vesting.released_amount,
+            vesting.start_time,
+            vesting.duration,
+            now,
+        )
+        .ok_or(NeobotsError::MathOverflow)?;
+
+        if releasable > 0 {
+            let nft_mint_key = ctx.accounts.nft_mint.key();
+            let bump = [vesting.bump];
+            let seeds = &[&[b"vesting", nft_mint_key.as_ref(), &bump][..]];
+
+            let cpi = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: vesting_token_account,
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: vesting.to_account_info(),
+                },
+                seeds,
+            );
+            transfer_checked(cpi, releasable, ctx.accounts.mint.decimals)?;
+        }
+
+        vesting.duration = vesting_schedule_duration(
+            locked,
+            vesting.start_time,
+            vesting.duration,
+            vesting_duration,
+            now,
+        )
+        .ok_or(NeobotsError::MathOverflow)?;
+        vesting.total_amount = locked
+            .checked_add(claim_amount)
+            .ok_or(NeobotsError::MathOverflow)?;
+        vesting.released_amount = 0;
+        vesting.start_time = now;
+    }
+
+    emit!(Claimed {
+        user: user.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        amount: claim_amount,
+        vested: vesting_duration > 0,
+    });
 
     Ok(())
 }
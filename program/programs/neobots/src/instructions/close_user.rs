@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{
+        self,
+        spl_token_2022::{
+            extension::{
+                transfer_fee::TransferFeeAmount, BaseStateWithExtensions, StateWithExtensions,
+            },
+            state::Account as TokenAccountState,
+        },
+    },
+    token_2022_extensions::{harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint},
+    token_interface::{
+        burn, close_account, transfer_checked, Burn, CloseAccount, Mint, TokenAccount,
+        TokenInterface, TransferChecked,
+    },
+};
+
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::types::UpdateAuthority;
+
+use crate::{Forum, NeobotsError, OperatorSession, User, UserClosed, UserCounter};
+
+// closes the user of the signer's NFT and its operator session, the rent goes
+// back to the owner.
+// claimable rewards block the close (UnclaimedRewards) instead of being
+// forfeited: the owner claims them first, so a close never drops tokens the
+// user earned. the same goes for the deposit left in the session
+// (OperatorSessionNotEmpty), it is withdrawn first. the balance charged by the
+// operator is never paid out of the session, it is forfeited (burned) so that
+// a charged session can still be closed. any other token left in the vault is
+// swept to the owner and the withheld Token-2022 fees are harvested to the
+// mint, so stray transfers to the vault cannot block the close either
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct CloseUser<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    #[account(
+        mut,
+        seeds = [b"usercounter"],
+        bump = user_counter.bump,
+    )]
+    pub user_counter: Account<'info, UserCounter>,
+
+    #[account(
+        mut,
+        seeds = [b"user", nft_mint.key().as_ref()],
+        bump = user.bump,
+        has_one = nft_mint,
+        close = owner,
+    )]
+    pub user: Account<'info, User>,
+
+    /// CHECK: the session PDA of the user, it may not exist. if it does it must be
+    /// empty and is closed with the user
+    #[account(
+        mut,
+        seeds = [b"operatorsession", user.key().as_ref()],
+        bump,
+    )]
+    pub operator_session: UncheckedAccount<'info>,
+
+    // vault of the operator session, closed with it. required when the session exists
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // required when the session exists
+    #[account(
+        mut,
+        address = forum.mint @ NeobotsError::InvalidMint,
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // receives what is left in the vault, required when the vault is not empty
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ NeobotsError::InvalidInput,
+        constraint = owner_token_account.mint == forum.mint @ NeobotsError::InvalidMint,
+    )]
+    pub owner_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = nft_mint.owner == owner.key() @ NeobotsError::NFTNotOwned,
+        constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
+    )]
+    pub nft_mint: Account<'info, BaseAssetV1>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_close_user(ctx: Context<CloseUser>, _forum_name: String) -> Result<()> {
+    let user = &ctx.accounts.user;
+
+    // rewards are not dropped with the account, claim them first
+    require!(user.claimable_amount == 0, NeobotsError::UnclaimedRewards);
+
+    let session_info = ctx.accounts.operator_session.to_account_info();
+
+    if !session_info.data_is_empty() {
+        let os = Account::<OperatorSession>::try_from(&session_info)?;
+
+        require!(
+            os.amount_for_user == 0,
+            NeobotsError::OperatorSessionNotEmpty
+        );
+
+        let vault = ctx
+            .accounts
+            .vault_token_account
+            .as_ref()
+            .ok_or(NeobotsError::InvalidInput)?;
+        let mint = ctx
+            .accounts
+            .mint
+            .as_ref()
+            .ok_or(NeobotsError::InvalidInput)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(NeobotsError::InvalidInput)?;
+        require_keys_eq!(vault.key(), os.vault, NeobotsError::InvalidInput);
+
+        let user_key = user.key();
+        let bump = [os.bump];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"operatorsession", user_key.as_ref(), &bump]];
+
+        // the operator balance is forfeited
+        let forfeited = os.amount_for_operator.min(vault.amount);
+        if forfeited > 0 {
+            burn(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Burn {
+                        mint: mint.to_account_info(),
+                        from: vault.to_account_info(),
+                        authority: session_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                forfeited,
+            )?;
+        }
+
+        // the rest was sent to the vault by someone else
+        let leftover = vault.amount - forfeited;
+        if leftover > 0 {
+            let owner_token_account = ctx
+                .accounts
+                .owner_token_account
+                .as_ref()
+                .ok_or(NeobotsError::InvalidInput)?;
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: vault.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: owner_token_account.to_account_info(),
+                        authority: session_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                leftover,
+                mint.decimals,
+            )?;
+        }
+
+        // a vault holding withheld transfer fees cannot be closed
+        if token_program.key() == token_2022::ID && withheld_fees(vault)? > 0 {
+            harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    HarvestWithheldTokensToMint {
+                        token_program_id: token_program.to_account_info(),
+                        mint: mint.to_account_info(),
+                    },
+                ),
+                vec![vault.to_account_info()],
+            )?;
+        }
+
+        // the rent of the vault and of the session goes back to the owner
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: session_info.clone(),
+            },
+            signer_seeds,
+        ))?;
+
+        os.close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    let user_counter = &mut ctx.accounts.user_counter;
+    user_counter.count = user_counter
+        .count
+        .checked_sub(1)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    emit!(UserClosed { user: user.key() });
+
+    Ok(())
+}
+
+fn withheld_fees(vault: &InterfaceAccount<TokenAccount>) -> Result<u64> {
+    let info = vault.to_account_info();
+    let data = info.try_borrow_data()?;
+    let state = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+
+    Ok(state
+        .get_extension::<TransferFeeAmount>()
+        .map(|fee| u64::from(fee.withheld_amount))
+        .unwrap_or(0))
+}
@@ -1,40 +1,41 @@
-use crate::{ActionPoints, RoundConfig, RoundStatus};
+use crate::{EmissionSchedule, SupplyConfig};
 
-// Note: all the values are just for testing
+use super::INITIAL_ROUND_STATUS;
 
+// the initial round parameters depend on the cluster, see `params`
+
+pub const TOKEN_NAME: &str = "Neobots";
+pub const TOKEN_SYMBOL: &str = "NBT";
+pub const TOKEN_URI: &str = "";
 pub const TOKEN_DECIMALS: u8 = 9;
 pub const TOKEN_UNIT: u64 = 1_000_000_000;
-pub const RATIO_SCALE: u64 = 1_000_000;
-pub const INFLATION_RATE: u64 = RATIO_SCALE / 10; // 10%
-
-pub const INITIAL_ACTION_POINTS: ActionPoints = ActionPoints {
-    post: 2,
-    comment: 10,
-    upvote: 30,
-    downvote: 10,
-    like: 30,
-    banvote: 10,
-};
-
-pub const INITIAL_ROUND_STATUS: RoundStatus = RoundStatus {
-    round_number: 0,
-    round_start_time: 0,
-    round_max_distribution: TOKEN_UNIT * 100, // 100 NBT
-    round_distribution_rate: RATIO_SCALE * 1, // 1x
-};
-
-pub const INITIAL_ROUND_CONFIG: RoundConfig = RoundConfig {
-    round_duration: 5 * 60,                        // 5 minutes
-    round_min_distribution_rate: RATIO_SCALE / 10, // min 0.1x
-    round_max_distribution_rate: RATIO_SCALE * 10, // max 10x
-
-    k_comment_receiver: TOKEN_UNIT / 10, // 0.1 NBT
-    k_comment: TOKEN_UNIT / 10,          // 0.1 NBT
-    k_quote: TOKEN_UNIT / 2,             // 0.5 NBT
-    k_reaction_giver: TOKEN_UNIT / 10,   // 0.1 NBT
-    k_reaction_receiver: TOKEN_UNIT / 2, // 0.5 NBT
-
-    decay_factor: RATIO_SCALE / 2, // 0.5x
-
-    default_action_points: INITIAL_ACTION_POINTS,
+pub use neobots_economics::RATIO_SCALE;
+
+// user profile limits in bytes. fields longer than the base length (the
+// `max_len` of User) are stored in an extension appended to the account.
+pub const PROFILE_FIELD_BASE_LEN: usize = 30;
+pub const MAX_NAME_LEN: usize = 30;
+pub const MAX_PERSONALITY_LEN: usize = 200;
+pub const MAX_THUMB_LEN: usize = 200;
+pub const PROFILE_EXTENSION_SPACE: usize =
+    (MAX_PERSONALITY_LEN - PROFILE_FIELD_BASE_LEN) + (MAX_THUMB_LEN - PROFILE_FIELD_BASE_LEN);
+
+// max length of the content store key or URI of posts and comments
+pub const MAX_CONTENT_URI_LEN: usize = 64;
+
+// max number of rounds tracked in User.reward_buckets
+pub const MAX_REWARD_BUCKETS: usize = 16;
+
+// number of finished rounds kept in RoundHistory
+pub const ROUND_HISTORY_LEN: usize = 32;
+
+// layout version of Forum, User, Post, Operator and OperatorSession.
+// accounts created before the version field are upgraded by migrate_account
+pub const ACCOUNT_VERSION: u8 = 1;
+
+pub const INITIAL_SUPPLY_CONFIG: SupplyConfig = SupplyConfig {
+    max_supply: TOKEN_UNIT * 1_000_000_000, // 1B NBT
+    max_supply_locked: false,
+    initial_round_emission: INITIAL_ROUND_STATUS.round_max_distribution,
+    emission_schedule: EmissionSchedule::Constant,
 };
@@ -6,15 +6,20 @@ use anchor_spl::{
 };
 use mpl_core::accounts::BaseAssetV1;
 use mpl_core::types::UpdateAuthority;
+use neobots_economics::spend_action_point;
 
-use crate::{Forum, NeobotsError, Post, Tag, User};
+use crate::{Forum, NeobotsError, Post, PostCreated, Tag, User};
 
-use super::reset_user_if_needed;
+use super::{
+    clear_stale_delegation, mark_user_active, reset_user_if_needed, validate_content_uri,
+    ACCOUNT_VERSION,
+};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String, content: String, tag_name: String)]
 pub struct CreatePost<'info> {
     #[account(
+        mut,
         seeds = [b"forum", forum_name.as_bytes()],
         bump = forum.bump,
     )]
@@ -51,6 +56,7 @@ pub struct CreatePost<'info> {
 
     #[account(
         constraint = (nft_mint.owner == owner.key() || user.operator == Some(owner.key())) @ NeobotsError::NFTNotOwned,
+        constraint = (nft_mint.owner == owner.key() || user.owner == nft_mint.owner) @ NeobotsError::OwnerMismatch,
         constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub nft_mint: Account<'info, BaseAssetV1>,
@@ -63,23 +69,42 @@ pub fn handle_create_post(
     _forum_name: String,
     content: String,
     _tag_name: String,
+    content_hash: [u8; 32],
+    content_uri: String,
 ) -> Result<()> {
-    let forum = &ctx.accounts.forum;
+    let forum = &mut ctx.accounts.forum;
     let user = &mut ctx.accounts.user;
     let tag = &mut ctx.accounts.tag;
 
+    validate_content_uri(&content_uri)?;
     reset_user_if_needed(user, forum)?;
+    clear_stale_delegation(user.key(), user, ctx.accounts.nft_mint.owner);
+    mark_user_active(user, forum)?;
 
-    if user.action_points.post < 1 {
-        return Err(NeobotsError::NotEnoughActionPoints.into());
-    }
+    user.action_points.post =
+        spend_action_point(user.action_points.post).ok_or(NeobotsError::NotEnoughActionPoints)?;
 
     let sequence = user.post_count;
 
-    msg!("{},{}", sequence, content,);
-
-    user.action_points.post -= 1;
-    user.post_count += 1;
+    emit!(PostCreated {
+        forum: forum.key(),
+        post: ctx.accounts.post.key(),
+        author: user.key(),
+        sequence,
+        content: content.clone(),
+        content_hash,
+        content_uri: content_uri.clone(),
+    });
+
+    user.post_count = user
+        .post_count
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
+    forum.round_activity.post_count = forum
+        .round_activity
+        .post_count
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
 
     *ctx.accounts.post = Post {
         author: user.key(),
@@ -87,7 +112,10 @@ pub fn handle_create_post(
         sequence,
         interactable: true,
         content,
+        content_hash,
+        content_uri,
         bump: ctx.bumps.post,
+        version: ACCOUNT_VERSION,
     };
 
     tag.set_inner(Tag {});
@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
-use anchor_spl::{associated_token::AssociatedToken, token::{transfer, Transfer}, token_interface::{Mint, TokenAccount, TokenInterface}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked}};
 use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority};
 
-use crate::{Forum, NeobotsError, Operator, OperatorSession, User};
+use crate::{Deposited, Forum, NeobotsError, Operator, OperatorSession, User};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String, operator: Pubkey, amount: u64)]
@@ -31,7 +31,7 @@ pub struct Deposit<'info> {
 
     #[account(
         mut,
-        seeds = [b"operator", operator.key().as_ref()],
+        seeds = [b"operator", operator.authority.as_ref()],
         bump = operator.bump,
     )]
     pub operator: Account<'info, Operator>,
@@ -56,7 +56,7 @@ pub struct Deposit<'info> {
         init_if_needed,
         payer = owner,
         associated_token::mint = mint,
-        associated_token::authority = owner,
+        associated_token::authority = operator_session,
         associated_token::token_program = token_program,
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
@@ -66,6 +66,8 @@ pub struct Deposit<'info> {
 
     #[account(
         constraint = (nft_mint.owner == owner.key() || user.operator == Some(owner.key())) @ NeobotsError::NFTNotOwned,
+        // the deposit belongs to the bound owner, a new holder rebinds first
+        constraint = user.owner == nft_mint.owner @ NeobotsError::OwnerMismatch,
         constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub nft_mint: Account<'info, BaseAssetV1>,
@@ -82,20 +84,40 @@ pub fn handle_deposit(
     amount: u64,
 ) -> Result<()> {
     let os = &mut ctx.accounts.operator_session;
-    require!(os.user == Pubkey::default(), NeobotsError::OperatorSessionNotInitialized);
+    require!(os.user != Pubkey::default(), NeobotsError::OperatorSessionNotInitialized);
 
     let cpi = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.vault_token_account.to_account_info(),
             authority: ctx.accounts.owner.to_account_info(),
         },
     );
 
-    os.amount_for_user += amount;
-
-    transfer(cpi, amount)?;
+    let vault_balance_before = ctx.accounts.vault_token_account.amount;
+
+    transfer_checked(cpi, amount, ctx.accounts.mint.decimals)?;
+
+    // only credit what the vault actually received (Token-2022 transfer fee)
+    ctx.accounts.vault_token_account.reload()?;
+    let credited = ctx
+        .accounts
+        .vault_token_account
+        .amount
+        .checked_sub(vault_balance_before)
+        .ok_or(NeobotsError::MathOverflow)?;
+    os.amount_for_user = os
+        .amount_for_user
+        .checked_add(credited)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    emit!(Deposited {
+        user: ctx.accounts.user.key(),
+        operator_session: os.key(),
+        amount: credited,
+    });
 
     Ok(())
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{Forum, User};
+
+use super::forfeit_expired_rewards;
+
+// permissionless: anyone can forfeit the expired rewards of an inactive bot
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct ExpireRewards<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    #[account(
+        mut,
+        seeds = [b"user", user.nft_mint.as_ref()],
+        bump = user.bump,
+    )]
+    pub user: Account<'info, User>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_expire_rewards(ctx: Context<ExpireRewards>, _forum_name: String) -> Result<()> {
+    let forum = &ctx.accounts.forum;
+    let user = &mut ctx.accounts.user;
+
+    forfeit_expired_rewards(user.key(), user, forum)
+}
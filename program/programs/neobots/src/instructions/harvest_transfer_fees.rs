@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_2022_extensions::{
+        harvest_withheld_tokens_to_mint, withdraw_withheld_tokens_from_mint,
+        HarvestWithheldTokensToMint, WithdrawWithheldTokensFromMint,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::Forum;
+
+// permissionless: moves the transfer fees withheld by the Token-2022 mint
+// to the treasury (the admin's token account).
+// remaining accounts: token accounts whose withheld fees are harvested first
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct HarvestTransferFees<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+        has_one = mint,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = forum.admin,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_harvest_transfer_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+    _forum_name: String,
+) -> Result<()> {
+    if !ctx.remaining_accounts.is_empty() {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            ctx.remaining_accounts.to_vec(),
+        )?;
+    }
+
+    // the mint PDA is the withdraw withheld authority
+    let seeds = &["mint".as_bytes(), &[ctx.bumps.mint]];
+    let signer = [&seeds[..]];
+
+    withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        WithdrawWithheldTokensFromMint {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            destination: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: ctx.accounts.mint.to_account_info(),
+        },
+        &signer,
+    ))?;
+
+    Ok(())
+}
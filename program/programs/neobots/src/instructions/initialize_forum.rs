@@ -8,9 +8,15 @@ use anchor_spl::{
 };
 use mpl_core::accounts::BaseCollectionV1;
 
-use super::{INITIAL_ROUND_CONFIG, INITIAL_ROUND_STATUS, TOKEN_DECIMALS};
+use super::{
+    validate_round_config, validate_round_status, ACCOUNT_VERSION, INITIAL_ROUND_CONFIG,
+    INITIAL_ROUND_STATUS, INITIAL_SUPPLY_CONFIG, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+    TOKEN_URI,
+};
 
-use crate::{Forum, NeobotsError, UserCounter};
+use crate::{
+    Forum, NeobotsError, RoundActivity, RoundConfig, RoundStatus, SupplyConfig, UserCounter,
+};
 
 use mpl_token_metadata::{instructions::CreateMetadataAccountV3, ID as TOKEN_METADATA_PROGRAM_ID};
 
@@ -64,25 +70,27 @@ pub struct InitializeForum<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_initialize_forum(ctx: Context<InitializeForum>, forum_name: String) -> Result<()> {
-    if forum_name != "forum_id" {
-        return Err(NeobotsError::InvalidForumName.into());
-    }
-
-    let forum = &mut ctx.accounts.forum;
-
-    forum.admin = ctx.accounts.payer.key();
-    forum.round_distributed = 0;
-    forum.round_status = INITIAL_ROUND_STATUS;
-    forum.round_config = INITIAL_ROUND_CONFIG;
-    forum.next_round_config = INITIAL_ROUND_CONFIG;
-    forum.bump = ctx.bumps.forum;
-    forum.mint = ctx.accounts.mint.key();
-    forum.nft_collection = ctx.accounts.nft_collection.key();
+pub fn handle_initialize_forum(
+    ctx: Context<InitializeForum>,
+    forum_name: String,
+    round_config: Option<RoundConfig>,
+    round_status: Option<RoundStatus>,
+) -> Result<()> {
+    initialize_forum_state(
+        &mut ctx.accounts.forum,
+        &forum_name,
+        ctx.accounts.payer.key(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.nft_collection.key(),
+        round_config,
+        round_status,
+        ctx.bumps.forum,
+    )?;
 
     *ctx.accounts.user_counter = UserCounter {
         count: 0,
         bump: ctx.bumps.user_counter,
+        version: ACCOUNT_VERSION,
     };
 
     /**
@@ -92,9 +100,9 @@ pub fn handle_initialize_forum(ctx: Context<InitializeForum>, forum_name: String
     let signer = [&seeds[..]];
 
     let token_data: DataV2 = DataV2 {
-        name: "Neobots".to_string(),
-        symbol: "NBT".to_string(),
-        uri: "".to_string(),
+        name: TOKEN_NAME.to_string(),
+        symbol: TOKEN_SYMBOL.to_string(),
+        uri: TOKEN_URI.to_string(),
         seller_fee_basis_points: 0,
         creators: None,
         collection: None,
@@ -119,3 +127,50 @@ pub fn handle_initialize_forum(ctx: Context<InitializeForum>, forum_name: String
 
     Ok(())
 }
+
+// shared by the legacy SPL and the Token-2022 forum initialization.
+// without a round config the forum starts with the values of the build profile,
+// without a round status the first round follows the round config.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_forum_state(
+    forum: &mut Forum,
+    forum_name: &str,
+    admin: Pubkey,
+    mint: Pubkey,
+    nft_collection: Pubkey,
+    round_config: Option<RoundConfig>,
+    round_status: Option<RoundStatus>,
+    bump: u8,
+) -> Result<()> {
+    if forum_name != "forum_id" {
+        return Err(NeobotsError::InvalidForumName.into());
+    }
+
+    let round_config = round_config.unwrap_or(INITIAL_ROUND_CONFIG);
+    validate_round_config(&round_config)?;
+
+    let round_status = round_status.unwrap_or(RoundStatus {
+        round_duration: round_config.round_duration,
+        ..INITIAL_ROUND_STATUS
+    });
+    validate_round_status(&round_status, &round_config)?;
+
+    forum.admin = admin;
+    forum.round_distributed = 0;
+    forum.supply_config = SupplyConfig {
+        initial_round_emission: round_status.round_max_distribution,
+        ..INITIAL_SUPPLY_CONFIG
+    };
+    forum.round_status = round_status;
+    forum.round_config = round_config.clone();
+    forum.next_round_config = round_config;
+    forum.round_activity = RoundActivity::default();
+    forum.last_round_activity = RoundActivity::default();
+    forum.last_advance_slot = 0;
+    forum.bump = bump;
+    forum.version = ACCOUNT_VERSION;
+    forum.mint = mint;
+    forum.nft_collection = nft_collection;
+
+    Ok(())
+}
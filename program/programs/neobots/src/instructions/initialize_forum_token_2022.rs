@@ -0,0 +1,194 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{create_account, CreateAccount},
+};
+use anchor_spl::{
+    token_2022::{
+        initialize_mint2,
+        spl_token_2022::{extension::ExtensionType, state::Mint as MintState},
+        InitializeMint2, Token2022,
+    },
+    token_2022_extensions::{
+        metadata_pointer_initialize, spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+        transfer_fee_initialize, MetadataPointerInitialize, TokenMetadataInitialize,
+        TransferFeeInitialize,
+    },
+};
+use mpl_core::accounts::BaseCollectionV1;
+
+use super::{
+    initialize_forum_state, ACCOUNT_VERSION, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_URI,
+};
+
+use crate::{Forum, RoundConfig, RoundStatus, UserCounter};
+
+// same as InitializeForum, but the reward token is a Token-2022 mint that holds
+// its own metadata (metadata-pointer + token-metadata extensions) and can charge
+// a transfer fee withheld for the treasury
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct InitializeForumToken2022<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"forum", forum_name.as_bytes()],
+        space = 8 + Forum::INIT_SPACE,
+        bump,
+    )]
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"usercounter"],
+        space = 8 + UserCounter::INIT_SPACE,
+        bump,
+    )]
+    pub user_counter: Account<'info, UserCounter>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump,
+    )]
+    /// CHECK: created and initialized as a Token-2022 mint in the handler
+    pub mint: UncheckedAccount<'info>,
+
+    pub nft_collection: Account<'info, BaseCollectionV1>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handle_initialize_forum_token_2022(
+    ctx: Context<InitializeForumToken2022>,
+    forum_name: String,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+    round_config: Option<RoundConfig>,
+    round_status: Option<RoundStatus>,
+) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+
+    initialize_forum_state(
+        &mut ctx.accounts.forum,
+        &forum_name,
+        ctx.accounts.payer.key(),
+        mint_key,
+        ctx.accounts.nft_collection.key(),
+        round_config,
+        round_status,
+        ctx.bumps.forum,
+    )?;
+
+    *ctx.accounts.user_counter = UserCounter {
+        count: 0,
+        bump: ctx.bumps.user_counter,
+        version: ACCOUNT_VERSION,
+    };
+
+    let seeds = &["mint".as_bytes(), &[ctx.bumps.mint]];
+    let signer = [&seeds[..]];
+
+    /**
+     * Create mint account with extensions
+     */
+    let mut extensions = vec![ExtensionType::MetadataPointer];
+    if transfer_fee_basis_points > 0 {
+        extensions.push(ExtensionType::TransferFeeConfig);
+    }
+
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(mint_key))?,
+        mint: mint_key,
+        name: TOKEN_NAME.to_string(),
+        symbol: TOKEN_SYMBOL.to_string(),
+        uri: TOKEN_URI.to_string(),
+        additional_metadata: vec![],
+    };
+
+    // the metadata is appended by the token program on initialization,
+    // so the account is funded for it upfront
+    let lamports = Rent::get()?.minimum_balance(space + token_metadata.tlv_size_of()?);
+
+    create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.mint.to_account_info(),
+            },
+            &signer,
+        ),
+        lamports,
+        space as u64,
+        &ctx.accounts.token_program.key(),
+    )?;
+
+    if transfer_fee_basis_points > 0 {
+        transfer_fee_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferFeeInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            Some(&mint_key),
+            Some(&mint_key),
+            transfer_fee_basis_points,
+            maximum_fee,
+        )?;
+    }
+
+    metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        Some(mint_key),
+        Some(mint_key),
+    )?;
+
+    initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        TOKEN_DECIMALS,
+        &mint_key,
+        Some(&mint_key),
+    )?;
+
+    // create metadata (stored in the mint itself)
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.mint.to_account_info(),
+                update_authority: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.mint.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &signer,
+        ),
+        token_metadata.name,
+        token_metadata.symbol,
+        token_metadata.uri,
+    )?;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{Forum, Operator, OperatorPool, OperatorPrice};
+use crate::{Forum, Operator, OperatorPool, OperatorPrice, ACCOUNT_VERSION};
 
 #[derive(Accounts)]
 #[instruction(
@@ -51,6 +51,7 @@ pub fn handle_initialize_operator(
     let operator = &mut ctx.accounts.operator;
     operator.authority = ctx.accounts.payer.key();
     operator.bump = ctx.bumps.operator;
+    operator.version = ACCOUNT_VERSION;
     operator.price = OperatorPrice {
         price_per_post,
         price_per_comment,
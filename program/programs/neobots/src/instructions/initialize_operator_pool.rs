@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{Forum, OperatorPool};
+use crate::{Forum, OperatorPool, ACCOUNT_VERSION};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String)]
@@ -33,6 +33,7 @@ pub fn handle_initialize_operator_pool(
     let operator_pool = &mut ctx.accounts.operator_pool;
     operator_pool.authority = ctx.accounts.payer.key();
     operator_pool.bump = ctx.bumps.operator_pool;
+    operator_pool.version = ACCOUNT_VERSION;
 
     Ok(())
 }
@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
 use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority};
 
+use super::{clear_stale_delegation, ACCOUNT_VERSION};
+
 use crate::{Forum, NeobotsError, Operator, OperatorSession, User};
 
 #[derive(Accounts)]
@@ -42,7 +44,7 @@ pub struct InitializeOperatorSession<'info> {
         init_if_needed,
         payer = owner,
         associated_token::mint = mint,
-        associated_token::authority = owner,
+        associated_token::authority = operator_session,
         associated_token::token_program = token_program,
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
@@ -52,6 +54,7 @@ pub struct InitializeOperatorSession<'info> {
 
     #[account(
         constraint = (nft_mint.owner == owner.key() || user.operator == Some(owner.key())) @ NeobotsError::NFTNotOwned,
+        constraint = (nft_mint.owner == owner.key() || user.owner == nft_mint.owner) @ NeobotsError::OwnerMismatch,
         constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub nft_mint: Account<'info, BaseAssetV1>,
@@ -66,6 +69,9 @@ pub fn handle_initialize_operator_session(
     _forum_name: String,
     _operator: Pubkey,
 ) -> Result<()> {
+    let user = &mut ctx.accounts.user;
+    clear_stale_delegation(user.key(), user, ctx.accounts.nft_mint.owner);
+
     let os = &mut ctx.accounts.operator_session;
     require!(os.user == Pubkey::default(), NeobotsError::OperatorSessionAlreadyInitialized);
 
@@ -74,6 +80,7 @@ pub fn handle_initialize_operator_session(
     os.amount_for_user = 0;
     os.amount_for_operator = 0;
     os.bump = ctx.bumps.operator_session;
+    os.version = ACCOUNT_VERSION;
 
     Ok(())
 }
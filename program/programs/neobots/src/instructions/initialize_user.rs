@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use mpl_core::accounts::BaseAssetV1;
 use mpl_core::types::UpdateAuthority;
 
-use super::INITIAL_ACTION_POINTS;
+use super::{user_account_space, validate_user_profile, ACCOUNT_VERSION};
 
 use crate::{Forum, NeobotsError, User, UserCounter};
 
@@ -27,7 +27,7 @@ pub struct InitializeUser<'info> {
         init,
         payer = payer,
         seeds = [b"user", nft_mint.key().as_ref()],
-        space = 8 + User::INIT_SPACE,
+        space = user_account_space(&personality, &name, &thumb),
         bump,
     )]
     pub user: Account<'info, User>,
@@ -51,11 +51,23 @@ pub fn handle_initialize_user(
     name: String,
     thumb: String,
 ) -> Result<()> {
+    validate_user_profile(&personality, &name, &thumb)?;
+
+    let action_points = ctx
+        .accounts
+        .forum
+        .round_config
+        .default_action_points
+        .clone();
+
     *ctx.accounts.user = User {
         nft_mint: ctx.accounts.nft_mint.key(),
+        owner: ctx.accounts.payer.key(),
         claimable_amount: 0,
         local_round_number: 0,
-        action_points: INITIAL_ACTION_POINTS,
+        last_active_round: None,
+        reward_buckets: Vec::new(),
+        action_points,
         interaction_metrics: Vec::new(),
         post_count: 0,
         comment_count: 0,
@@ -75,9 +87,15 @@ pub fn handle_initialize_user(
         thumb,
         operator: None,
         bump: ctx.bumps.user,
+        version: ACCOUNT_VERSION,
     };
 
-    ctx.accounts.user_counter.count += 1;
+    ctx.accounts.user_counter.count = ctx
+        .accounts
+        .user_counter
+        .count
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
 
     Ok(())
 }
@@ -0,0 +1,205 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+use mpl_core::accounts::BaseAssetV1;
+
+use super::{user_account_space, ACCOUNT_VERSION};
+
+use crate::{
+    AccountMigrated, Forum, ForumV0, NeobotsError, Operator, OperatorPool, OperatorPoolV0,
+    OperatorSession, OperatorSessionV0, OperatorV0, Post, PostV0, User, UserCounter, UserCounterV0,
+    UserV0,
+};
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: may still hold the legacy layout, only its admin is read
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump,
+    )]
+    pub forum: UncheckedAccount<'info>,
+
+    /// CHECK: Forum, User, Post, Operator, OperatorSession, UserCounter or
+    /// OperatorPool of the program, the layout is picked from the discriminator.
+    /// Vesting and RoundHistory did not exist in the first release
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    /// CHECK: author of the post or user of the operator session to migrate,
+    /// its owner may migrate them. checked in the handler
+    pub user: Option<UncheckedAccount<'info>>,
+
+    // NFT of the user to migrate, its holder becomes the owner of the user
+    pub nft_mint: Option<Account<'info, BaseAssetV1>>,
+
+    // the forum admin or the owner of the account, pays the larger rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_account(ctx: Context<MigrateAccount>, _forum_name: String) -> Result<()> {
+    let admin = read_prefix::<Pubkey>(&ctx.accounts.forum, &Forum::DISCRIMINATOR)?;
+    let account_info = ctx.accounts.account.to_account_info();
+
+    // owner of the account, space of the current layout and its serialization
+    let (owner, space, migrated) = {
+        let data = account_info.try_borrow_data()?;
+        require!(data.len() >= 8, NeobotsError::InvalidAccountType);
+
+        let mut migrated = Vec::new();
+        let discriminator = &data[..8];
+
+        let (owner, space) = if discriminator == Forum::DISCRIMINATOR {
+            let forum = upgrade::<ForumV0, Forum>(&data, |forum| forum.version)?;
+            forum.try_serialize(&mut migrated)?;
+
+            (forum.admin, 8 + Forum::INIT_SPACE)
+        } else if discriminator == User::DISCRIMINATOR {
+            let mut user = upgrade::<UserV0, User>(&data, |user| user.version)?;
+
+            // the legacy layout does not record the owner
+            let nft_mint = ctx
+                .accounts
+                .nft_mint
+                .as_ref()
+                .ok_or(NeobotsError::InvalidInput)?;
+            require_keys_eq!(nft_mint.key(), user.nft_mint, NeobotsError::InvalidInput);
+            user.owner = nft_mint.owner;
+
+            user.try_serialize(&mut migrated)?;
+
+            (
+                user.owner,
+                user_account_space(&user.personality, &user.name, &user.thumb),
+            )
+        } else if discriminator == Post::DISCRIMINATOR {
+            let post = upgrade::<PostV0, Post>(&data, |post| post.version)?;
+            post.try_serialize(&mut migrated)?;
+
+            (
+                user_owner(&ctx.accounts.user, post.author)?,
+                8 + Post::INIT_SPACE,
+            )
+        } else if discriminator == Operator::DISCRIMINATOR {
+            let operator = upgrade::<OperatorV0, Operator>(&data, |operator| operator.version)?;
+            operator.try_serialize(&mut migrated)?;
+
+            (operator.authority, 8 + Operator::INIT_SPACE)
+        } else if discriminator == OperatorSession::DISCRIMINATOR {
+            let session =
+                upgrade::<OperatorSessionV0, OperatorSession>(&data, |session| session.version)?;
+            session.try_serialize(&mut migrated)?;
+
+            (
+                user_owner(&ctx.accounts.user, session.user)?,
+                8 + OperatorSession::INIT_SPACE,
+            )
+        } else if discriminator == UserCounter::DISCRIMINATOR {
+            let counter = upgrade::<UserCounterV0, UserCounter>(&data, |counter| counter.version)?;
+            counter.try_serialize(&mut migrated)?;
+
+            // nobody owns the counter, only the admin migrates it
+            (admin, 8 + UserCounter::INIT_SPACE)
+        } else if discriminator == OperatorPool::DISCRIMINATOR {
+            let pool = upgrade::<OperatorPoolV0, OperatorPool>(&data, |pool| pool.version)?;
+            pool.try_serialize(&mut migrated)?;
+
+            (pool.authority, 8 + OperatorPool::INIT_SPACE)
+        } else {
+            return err!(NeobotsError::InvalidAccountType);
+        };
+
+        (owner, space, migrated)
+    };
+
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == admin || authority == owner,
+        NeobotsError::AccessDenied
+    );
+
+    // never shrink, the tail of a legacy account may be its reserved space
+    let space = space.max(migrated.len()).max(account_info.data_len());
+
+    if space > account_info.data_len() {
+        let required_lamports = Rent::get()?.minimum_balance(space);
+
+        if required_lamports > account_info.lamports() {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                required_lamports - account_info.lamports(),
+            )?;
+        }
+
+        account_info.realloc(space, false)?;
+    }
+
+    account_info.try_borrow_mut_data()?[..migrated.len()].copy_from_slice(&migrated);
+
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        version: ACCOUNT_VERSION,
+    });
+
+    Ok(())
+}
+
+// current layout of the account, read from its legacy layout. an account that
+// already deserializes with the current version has nothing to migrate
+fn upgrade<L, T>(data: &[u8], version: impl Fn(&T) -> u8) -> Result<T>
+where
+    L: AnchorDeserialize + Into<T>,
+    T: AccountDeserialize,
+{
+    if let Ok(account) = T::try_deserialize(&mut &data[..]) {
+        require!(
+            version(&account) != ACCOUNT_VERSION,
+            NeobotsError::AccountUpToDate
+        );
+    }
+
+    let legacy =
+        L::deserialize(&mut &data[8..]).map_err(|_| error!(NeobotsError::InvalidAccountType))?;
+
+    Ok(legacy.into())
+}
+
+// first fields of the account, the same in its legacy and current layouts
+fn read_prefix<P: AnchorDeserialize>(info: &AccountInfo, discriminator: &[u8]) -> Result<P> {
+    require_keys_eq!(*info.owner, crate::ID, NeobotsError::InvalidAccountType);
+
+    let data = info.try_borrow_data()?;
+    require!(
+        data.starts_with(discriminator),
+        NeobotsError::InvalidAccountType
+    );
+
+    P::deserialize(&mut &data[8..]).map_err(|_| error!(NeobotsError::InvalidAccountType))
+}
+
+// owner of the user account `key`, passed as `user`. only a migrated user
+// records its owner, so the user is migrated before its posts and session
+fn user_owner(user: &Option<UncheckedAccount>, key: Pubkey) -> Result<Pubkey> {
+    let user = user.as_ref().ok_or(NeobotsError::InvalidInput)?;
+    require_keys_eq!(user.key(), key, NeobotsError::InvalidInput);
+    require_keys_eq!(*user.owner, crate::ID, NeobotsError::InvalidAccountType);
+
+    let data = user.try_borrow_data()?;
+    let user =
+        User::try_deserialize(&mut &data[..]).map_err(|_| error!(NeobotsError::InvalidInput))?;
+    require!(user.version == ACCOUNT_VERSION, NeobotsError::InvalidInput);
+
+    Ok(user.owner)
+}
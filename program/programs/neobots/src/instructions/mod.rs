@@ -16,6 +16,9 @@ pub use reset_user_action_points::*;
 pub mod constants;
 pub use constants::*;
 
+pub mod params;
+pub use params::*;
+
 pub mod create_post;
 pub use create_post::*;
 
@@ -53,4 +56,37 @@ pub mod set_operator;
 pub use set_operator::*;
 
 pub mod operator_add_comment;
-pub use operator_add_comment::*;
\ No newline at end of file
+pub use operator_add_comment::*;
+
+pub mod update_round_config;
+pub use update_round_config::*;
+
+pub mod release_vested;
+pub use release_vested::*;
+
+pub mod expire_rewards;
+pub use expire_rewards::*;
+
+pub mod update_supply_config;
+pub use update_supply_config::*;
+
+pub mod update_token_metadata;
+pub use update_token_metadata::*;
+
+pub mod initialize_forum_token_2022;
+pub use initialize_forum_token_2022::*;
+
+pub mod harvest_transfer_fees;
+pub use harvest_transfer_fees::*;
+
+pub mod update_user_profile;
+pub use update_user_profile::*;
+
+pub mod rebind_owner;
+pub use rebind_owner::*;
+
+pub mod close_user;
+pub use close_user::*;
+
+pub mod migrate_account;
+pub use migrate_account::*;
@@ -2,15 +2,22 @@ use anchor_lang::prelude::*;
 
 use mpl_core::accounts::BaseAssetV1;
 use mpl_core::types::UpdateAuthority;
+use neobots_economics::spend_action_point;
 
-use crate::{Forum, NeobotsError, Operator, OperatorSession, Post, User};
+use crate::{
+    CommentAdded, Forum, NeobotsError, Operator, OperatorCharged, OperatorSession, Post, User,
+};
 
-use super::{calculate_reward, distribute_reward, reset_user_if_needed};
+use super::{
+    calculate_reward, clear_stale_delegation, distribute_reward, mark_user_active,
+    reset_user_if_needed, validate_content_uri,
+};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String, post_sequence: u32, content: String)]
 pub struct OperatorAddComment<'info> {
     #[account(
+        mut,
         seeds = [b"forum", forum_name.as_bytes()],
         bump = forum.bump,
     )]
@@ -23,6 +30,7 @@ pub struct OperatorAddComment<'info> {
     )]
     pub post: Account<'info, Post>,
 
+    #[account(mut)]
     pub post_author: Account<'info, User>,
 
     #[account(
@@ -32,15 +40,18 @@ pub struct OperatorAddComment<'info> {
     )]
     pub sender_user: Account<'info, User>,
 
+    // the sender is the owner of the NFT or the wallet of the operator the
+    // user delegated to (user.operator holds the operator PDA)
     #[account(
-        constraint = (sender_nft_mint.owner == sender.key() || sender_user.operator == Some(sender.key())) @ NeobotsError::NFTNotOwned,
+        constraint = (sender_nft_mint.owner == sender.key() || operator.authority == sender.key()) @ NeobotsError::NFTNotOwned,
+        constraint = (sender_nft_mint.owner == sender.key() || sender_user.owner == sender_nft_mint.owner) @ NeobotsError::OwnerMismatch,
         constraint = sender_nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub sender_nft_mint: Account<'info, BaseAssetV1>,
 
     #[account(
-        seeds = [b"operator", operator.key().as_ref()],
-        constraint = operator_session.operator == operator.key() && operator_session.operator == sender_user.operator.unwrap() @ NeobotsError::OperatorKeyMismatch,
+        seeds = [b"operator", operator.authority.as_ref()],
+        constraint = operator_session.operator == operator.key() && sender_user.operator == Some(operator_session.operator) @ NeobotsError::OperatorKeyMismatch,
         bump = operator.bump,
     )]
     pub operator: Account<'info, Operator>,
@@ -63,46 +74,81 @@ pub fn handle_operator_add_comment(
     _forum_name: String,
     _post_sequence: u32,
     content: String,
+    content_hash: [u8; 32],
+    content_uri: String,
 ) -> Result<()> {
-    let forum = &ctx.accounts.forum;
+    let forum = &mut ctx.accounts.forum;
     let sender_user = &mut ctx.accounts.sender_user;
     let post_author = &mut ctx.accounts.post_author;
     let operator_session = &mut ctx.accounts.operator_session;
     let operator = &mut ctx.accounts.operator;
 
+    validate_content_uri(&content_uri)?;
     reset_user_if_needed(sender_user, forum)?;
+    clear_stale_delegation(sender_user.key(), sender_user, ctx.accounts.sender_nft_mint.owner);
+    mark_user_active(sender_user, forum)?;
 
-    if sender_user.action_points.comment < 1 {
-        return Err(NeobotsError::NotEnoughActionPoints.into());
-    }
+    sender_user.action_points.comment = spend_action_point(sender_user.action_points.comment)
+        .ok_or(NeobotsError::NotEnoughActionPoints)?;
 
     // move user funds to operator virtually
     require!(operator_session.amount_for_user >= operator.price.price_per_comment, NeobotsError::InsufficientFunds);
-    operator_session.amount_for_user -= operator.price.price_per_comment;
-    operator_session.amount_for_operator += operator.price.price_per_comment;
-
-    sender_user.action_points.comment -= 1;
-    sender_user.comment_count += 1;
+    operator_session.amount_for_user = operator_session
+        .amount_for_user
+        .checked_sub(operator.price.price_per_comment)
+        .ok_or(NeobotsError::MathOverflow)?;
+    operator_session.amount_for_operator = operator_session
+        .amount_for_operator
+        .checked_add(operator.price.price_per_comment)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    emit!(OperatorCharged {
+        user: sender_user.key(),
+        operator: operator_session.operator,
+        amount: operator.price.price_per_comment,
+    });
+
+    sender_user.comment_count = sender_user
+        .comment_count
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
+    forum.round_activity.comment_count = forum
+        .round_activity
+        .comment_count
+        .checked_add(1)
+        .ok_or(NeobotsError::MathOverflow)?;
 
     // incentive for the commenter
-    let reward = calculate_reward(forum, forum.round_config.k_comment);
+    let reward = calculate_reward(forum, forum.round_config.k_comment)?;
     distribute_reward(
         sender_user.key(),
         sender_user,
+        forum,
         reward,
         "comment_creator".to_string(),
     )?;
 
     // incentive for the post author
-    let reward = calculate_reward(forum, forum.round_config.k_comment_receiver);
+    let reward = calculate_reward(forum, forum.round_config.k_comment_receiver)?;
     distribute_reward(
         post_author.key(),
         post_author,
+        forum,
         reward,
         "comment_receiver".to_string(),
     )?;
 
-    msg!("{},{}", sender_user.comment_count, content,);
+    emit!(CommentAdded {
+        forum: forum.key(),
+        post: ctx.accounts.post.key(),
+        post_author: post_author.key(),
+        author: sender_user.key(),
+        sequence: sender_user.comment_count,
+        content,
+        content_hash,
+        content_uri,
+        operator: Some(operator_session.operator),
+    });
 
     Ok(())
 }
@@ -0,0 +1,44 @@
+//! Test values: short rounds so tests and local validators see several rounds.
+
+use crate::{ActionPoints, RoundConfig, RoundDurationMode, RoundStatus, RATIO_SCALE, TOKEN_UNIT};
+
+pub const INITIAL_ACTION_POINTS: ActionPoints = ActionPoints {
+    post: 2,
+    comment: 10,
+    upvote: 30,
+    downvote: 10,
+    like: 30,
+    banvote: 10,
+};
+
+pub const INITIAL_ROUND_STATUS: RoundStatus = RoundStatus {
+    round_number: 0,
+    round_start_time: 0,
+    round_max_distribution: TOKEN_UNIT * 100, // 100 NBT
+    round_distribution_rate: RATIO_SCALE * 1, // 1x
+    round_duration: INITIAL_ROUND_CONFIG.round_duration,
+};
+
+pub const INITIAL_ROUND_CONFIG: RoundConfig = RoundConfig {
+    round_duration: 5 * 60,                        // 5 minutes
+    round_min_distribution_rate: RATIO_SCALE / 10, // min 0.1x
+    round_max_distribution_rate: RATIO_SCALE * 10, // max 10x
+
+    k_comment_receiver: TOKEN_UNIT / 10, // 0.1 NBT
+    k_comment: TOKEN_UNIT / 10,          // 0.1 NBT
+    k_quote: TOKEN_UNIT / 2,             // 0.5 NBT
+    k_reaction_giver: TOKEN_UNIT / 10,   // 0.1 NBT
+    k_reaction_receiver: TOKEN_UNIT / 2, // 0.5 NBT
+
+    decay_factor: RATIO_SCALE / 2, // 0.5x
+
+    round_duration_mode: RoundDurationMode::Fixed,
+
+    default_action_points: INITIAL_ACTION_POINTS,
+
+    vesting_duration: 0, // instant unlock
+
+    claim_window_rounds: 0, // never expire
+
+    crank_reward: TOKEN_UNIT / 10, // 0.1 NBT
+};
@@ -0,0 +1,53 @@
+//! Launch values. Rounds grow from 1 to 24 hours with the active bots, action
+//! points follow the distribution of `tokenomics/config.py`.
+
+use crate::{
+    ActionPoints, DurationCurve, RoundConfig, RoundDurationMode, RoundStatus, RATIO_SCALE,
+    TOKEN_UNIT,
+};
+
+pub const INITIAL_ACTION_POINTS: ActionPoints = ActionPoints {
+    post: 1,
+    comment: 10,
+    upvote: 5,
+    downvote: 2,
+    like: 20,
+    banvote: 1,
+};
+
+pub const INITIAL_ROUND_STATUS: RoundStatus = RoundStatus {
+    round_number: 0,
+    round_start_time: 0,
+    round_max_distribution: TOKEN_UNIT * 1_200, // 1200 NBT
+    round_distribution_rate: RATIO_SCALE * 1,   // 1x
+    round_duration: INITIAL_ROUND_CONFIG.round_duration,
+};
+
+pub const INITIAL_ROUND_CONFIG: RoundConfig = RoundConfig {
+    round_duration: 60 * 60,                           // 1 hour
+    round_min_distribution_rate: RATIO_SCALE * 6 / 10, // min 0.6x
+    round_max_distribution_rate: RATIO_SCALE,          // max 1x
+
+    k_comment_receiver: TOKEN_UNIT / 10, // 0.1 NBT
+    k_comment: TOKEN_UNIT / 10,          // 0.1 NBT
+    k_quote: TOKEN_UNIT / 2,             // 0.5 NBT
+    k_reaction_giver: TOKEN_UNIT / 10,   // 0.1 NBT
+    k_reaction_receiver: TOKEN_UNIT / 2, // 0.5 NBT
+
+    decay_factor: RATIO_SCALE / 2, // 0.5x
+
+    round_duration_mode: RoundDurationMode::Adaptive {
+        min_duration: 60 * 60,      // 1 hour
+        max_duration: 24 * 60 * 60, // 24 hours
+        users_at_max: 300_000,
+        curve: DurationCurve::Log,
+    },
+
+    default_action_points: INITIAL_ACTION_POINTS,
+
+    vesting_duration: 7 * 24 * 60 * 60, // 1 week
+
+    claim_window_rounds: 8,
+
+    crank_reward: TOKEN_UNIT / 10, // 0.1 NBT
+};
@@ -0,0 +1,88 @@
+//! Initial forum parameters of each cluster, picked at build time:
+//!
+//! - `localnet`: 5 minute rounds for tests and local validators
+//! - `devnet`: 30 minute rounds, still test values
+//! - `mainnet`: the launch values
+//!
+//! Exactly one of them is enabled, there is no default:
+//! `anchor build -- --features mainnet` builds the mainnet program.
+
+use crate::{RoundConfig, RoundDurationMode};
+
+pub mod devnet;
+pub mod localnet;
+pub mod mainnet;
+
+#[cfg(not(any(feature = "localnet", feature = "devnet", feature = "mainnet")))]
+compile_error!("enable one of the `localnet`, `devnet` or `mainnet` features");
+
+#[cfg(all(feature = "localnet", feature = "devnet"))]
+compile_error!("the `localnet` and `devnet` features select different parameters, enable one");
+
+#[cfg(all(feature = "mainnet", any(feature = "localnet", feature = "devnet")))]
+compile_error!("the `mainnet` feature cannot be combined with the `localnet` or `devnet` features");
+
+#[cfg(feature = "localnet")]
+pub use localnet::{INITIAL_ACTION_POINTS, INITIAL_ROUND_CONFIG, INITIAL_ROUND_STATUS};
+
+#[cfg(feature = "devnet")]
+pub use devnet::{INITIAL_ACTION_POINTS, INITIAL_ROUND_CONFIG, INITIAL_ROUND_STATUS};
+
+#[cfg(feature = "mainnet")]
+pub use mainnet::{INITIAL_ACTION_POINTS, INITIAL_ROUND_CONFIG, INITIAL_ROUND_STATUS};
+
+// shortest round a production forum starts with, anything below is a test value
+pub const MIN_PRODUCTION_ROUND_DURATION: u64 = 60 * 60;
+
+// whether `config` has the short rounds of the test profiles
+pub const fn is_test_round_config(config: &RoundConfig) -> bool {
+    let min_duration = match config.round_duration_mode {
+        RoundDurationMode::Fixed => config.round_duration,
+        RoundDurationMode::Adaptive { min_duration, .. } => min_duration,
+    };
+
+    config.round_duration < MIN_PRODUCTION_ROUND_DURATION
+        || min_duration < MIN_PRODUCTION_ROUND_DURATION
+}
+
+// test values must not be compiled into a mainnet build. checked in every
+// build, so an edit of the mainnet values fails on localnet too
+const _: () = {
+    assert!(
+        !is_test_round_config(&mainnet::INITIAL_ROUND_CONFIG),
+        "the mainnet profile has test round parameters"
+    );
+    assert!(
+        mainnet::INITIAL_ROUND_STATUS.round_duration
+            == mainnet::INITIAL_ROUND_CONFIG.round_duration,
+        "the first mainnet round does not follow the mainnet round config"
+    );
+
+    let config = &mainnet::INITIAL_ROUND_CONFIG;
+    let status = &mainnet::INITIAL_ROUND_STATUS;
+    assert!(
+        status.round_number == 0 && status.round_start_time == 0,
+        "the mainnet forum does not start at round 0"
+    );
+    assert!(
+        config.round_min_distribution_rate <= status.round_distribution_rate
+            && status.round_distribution_rate <= config.round_max_distribution_rate,
+        "the first mainnet distribution rate is out of the mainnet bounds"
+    );
+    assert!(
+        status.round_max_distribution > localnet::INITIAL_ROUND_STATUS.round_max_distribution,
+        "the mainnet round emission is not above the test emission"
+    );
+    assert!(
+        config.crank_reward <= status.round_max_distribution,
+        "the mainnet crank reward is larger than a round"
+    );
+    assert!(
+        config.vesting_duration >= MIN_PRODUCTION_ROUND_DURATION,
+        "the mainnet rewards vest for less than a production round"
+    );
+    assert!(
+        config.claim_window_rounds > 0,
+        "the mainnet rewards never expire"
+    );
+};
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::types::UpdateAuthority;
+
+use crate::{Forum, NeobotsError, OperatorSession, OwnerRebound, User};
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct RebindOwner<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    #[account(
+        mut,
+        seeds = [b"user", nft_mint.key().as_ref()],
+        bump = user.bump,
+        has_one = nft_mint,
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        constraint = nft_mint.owner == new_owner.key() @ NeobotsError::NFTNotOwned,
+        constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
+    )]
+    pub nft_mint: Account<'info, BaseAssetV1>,
+
+    /// CHECK: the session PDA of the user, it may not exist. if it does its
+    /// operator is reset, so it is always passed
+    #[account(
+        mut,
+        seeds = [b"operatorsession", user.key().as_ref()],
+        bump,
+    )]
+    pub operator_session: UncheckedAccount<'info>,
+
+    pub new_owner: Signer<'info>,
+}
+
+// the user account follows the NFT: its claimable rewards and the deposit of
+// its operator session go to the new holder. the previous owner claims and
+// withdraws them before transferring the NFT
+pub fn handle_rebind_owner(ctx: Context<RebindOwner>, _forum_name: String) -> Result<()> {
+    let user = &mut ctx.accounts.user;
+    user.owner = ctx.accounts.new_owner.key();
+    // the delegates of the previous owner act for the new one no more
+    user.operator = None;
+
+    let session_info = ctx.accounts.operator_session.to_account_info();
+    if !session_info.data_is_empty() {
+        let mut os = Account::<OperatorSession>::try_from(&session_info)?;
+        os.operator = Pubkey::default();
+        os.exit(&crate::ID)?;
+    }
+
+    emit!(OwnerRebound {
+        user: user.key(),
+        owner: user.owner,
+    });
+
+    Ok(())
+}
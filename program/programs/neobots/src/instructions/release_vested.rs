@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::types::UpdateAuthority;
+
+use crate::{Forum, NeobotsError, Vesting, VestingReleased};
+
+use super::vested_amount;
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct ReleaseVested<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+        has_one = mint,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting", nft_mint.key().as_ref()],
+        bump = vesting.bump,
+        has_one = nft_mint,
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = nft_mint.owner == beneficiary.key() @ NeobotsError::NFTNotOwned,
+        constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
+    )]
+    pub nft_mint: Account<'info, BaseAssetV1>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"mint"],
+        bump
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_release_vested(ctx: Context<ReleaseVested>, _forum_name: String) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting;
+    let now = Clock::get()?.unix_timestamp;
+
+    let releasable = vested_amount(vesting, now)
+        .checked_sub(vesting.released_amount)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    if releasable == 0 {
+        return Err(NeobotsError::NothingToRelease.into());
+    }
+
+    let nft_mint_key = ctx.accounts.nft_mint.key();
+    let bump = [vesting.bump];
+    let seeds = &[&[b"vesting", nft_mint_key.as_ref(), &bump][..]];
+
+    let cpi = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vesting_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: vesting.to_account_info(),
+        },
+        seeds,
+    );
+    transfer_checked(cpi, releasable, ctx.accounts.mint.decimals)?;
+
+    vesting.released_amount = vesting
+        .released_amount
+        .checked_add(releasable)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    emit!(VestingReleased {
+        nft_mint: nft_mint_key,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        amount: releasable,
+    });
+
+    Ok(())
+}
@@ -5,7 +5,7 @@ use mpl_core::types::UpdateAuthority;
 
 use crate::{Forum, NeobotsError, Post, User};
 
-use super::{calculate_reward, distribute_reward, reset_user_if_needed};
+use super::{calculate_reward, clear_stale_delegation, distribute_reward, reset_user_if_needed};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String)]
@@ -25,6 +25,7 @@ pub struct ResetUserActionPoints<'info> {
 
     #[account(
         constraint = (nft_mint.owner == signer.key() || user.operator == Some(signer.key())) @ NeobotsError::NFTNotOwned,
+        constraint = (nft_mint.owner == signer.key() || user.owner == nft_mint.owner) @ NeobotsError::OwnerMismatch,
         constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub nft_mint: Account<'info, BaseAssetV1>,
@@ -43,6 +44,7 @@ pub fn handle_reset_user_action_points(
     let user = &mut ctx.accounts.user;
 
     reset_user_if_needed(user, forum)?;
+    clear_stale_delegation(user.key(), user, ctx.accounts.nft_mint.owner);
 
     msg!(
         "type=user,post={},comment={},upvote={},downvote={},like={},banvote={}",
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 
 use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority};
 
+use super::clear_stale_delegation;
+
 use crate::{Forum, NeobotsError, Operator, OperatorSession, User};
 
 #[derive(Accounts)]
@@ -23,7 +25,7 @@ pub struct SetOperator<'info> {
 
     #[account(
         mut,
-        seeds = [b"operator", operator.key().as_ref()],
+        seeds = [b"operator", operator.authority.as_ref()],
         bump = operator.bump,
     )]
     pub operator: Account<'info, Operator>,
@@ -40,6 +42,7 @@ pub struct SetOperator<'info> {
 
     #[account(
         constraint = (nft_mint.owner == owner.key() || user.operator == Some(owner.key())) @ NeobotsError::NFTNotOwned,
+        constraint = (nft_mint.owner == owner.key() || user.owner == nft_mint.owner) @ NeobotsError::OwnerMismatch,
         constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub nft_mint: Account<'info, BaseAssetV1>,
@@ -52,6 +55,10 @@ pub fn handle_set_operator(
     _forum_name: String,
     _operator: Pubkey,
 ) -> Result<()> {
+    // a stale operator cannot pick the session operator of the new owner
+    let user = &mut ctx.accounts.user;
+    clear_stale_delegation(user.key(), user, ctx.accounts.nft_mint.owner);
+
     let os = &mut ctx.accounts.operator_session;
     os.operator = ctx.accounts.operator.key();
 
@@ -25,6 +25,7 @@ pub struct SetUserOperator<'info> {
 
     #[account(
         constraint = nft_mint.owner == payer.key() @ NeobotsError::NFTNotOwned,
+        constraint = user.owner == nft_mint.owner @ NeobotsError::OwnerMismatch,
         constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub nft_mint: Account<'info, BaseAssetV1>,
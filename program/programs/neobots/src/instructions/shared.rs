@@ -1,11 +1,22 @@
 use anchor_lang::prelude::*;
+use neobots_economics::{
+    decay_emission, halving_emission, is_reward_expired, is_valid_decay_rate,
+    linear_round_duration, log_round_duration, needs_action_point_reset,
+};
 
-use crate::{Forum, User};
+use crate::{
+    DelegationCleared, DurationCurve, EmissionSchedule, Forum, NeobotsError, RewardBucket,
+    RewardDistributed, RewardsForfeited, RoundConfig, RoundDurationMode, RoundHistory,
+    RoundHistoryEntry, RoundStatus, SupplyConfig, User, Vesting,
+};
 
-use super::RATIO_SCALE;
+use super::{
+    MAX_CONTENT_URI_LEN, MAX_NAME_LEN, MAX_PERSONALITY_LEN, MAX_REWARD_BUCKETS, MAX_THUMB_LEN,
+    PROFILE_EXTENSION_SPACE, PROFILE_FIELD_BASE_LEN, RATIO_SCALE, ROUND_HISTORY_LEN,
+};
 
 pub fn reset_user_if_needed(user: &mut User, forum: &Forum) -> Result<()> {
-    if user.local_round_number < forum.round_status.round_number {
+    if needs_action_point_reset(user.local_round_number, forum.round_status.round_number) {
         user.action_points = forum.round_config.default_action_points.clone();
         user.interaction_metrics.clear();
         user.local_round_number = forum.round_status.round_number;
@@ -15,21 +26,297 @@ pub fn reset_user_if_needed(user: &mut User, forum: &Forum) -> Result<()> {
     Ok(())
 }
 
-pub fn calculate_reward(forum: &Forum, k: u64) -> u64 {
-    // k * round_distribution_rate / RATIO_SCALE
-    k.saturating_mul(forum.round_status.round_distribution_rate)
-        .saturating_div(RATIO_SCALE)
+// count the user as active the first time it acts in the current round
+pub fn mark_user_active(user: &mut User, forum: &mut Forum) -> Result<()> {
+    let round_number = forum.round_status.round_number;
+
+    if user.last_active_round != Some(round_number) {
+        user.last_active_round = Some(round_number);
+        forum.round_activity.active_users = forum
+            .round_activity
+            .active_users
+            .checked_add(1)
+            .ok_or(NeobotsError::MathOverflow)?;
+    }
+
+    Ok(())
+}
+
+pub fn calculate_reward(forum: &Forum, k: u64) -> Result<u64> {
+    let reward = neobots_economics::calculate_reward(k, forum.round_status.round_distribution_rate)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    Ok(reward)
 }
 
 pub fn distribute_reward(
     user_key: Pubkey,
     user: &mut User,
+    forum: &Forum,
     reward: u64,
     reason: String,
 ) -> Result<()> {
-    user.claimable_amount += reward;
+    forfeit_expired_rewards(user_key, user, forum)?;
+
+    user.claimable_amount = user
+        .claimable_amount
+        .checked_add(reward)
+        .ok_or(NeobotsError::MathOverflow)?;
 
-    msg!("reward,{},{},{}", user_key.to_string(), reward, reason);
+    if forum.round_config.claim_window_rounds > 0 {
+        let round_number = forum.round_status.round_number;
+        // cannot happen with a validated claim window, but never drop a reward
+        let is_full = user.reward_buckets.len() >= MAX_REWARD_BUCKETS;
+
+        match user.reward_buckets.last_mut() {
+            Some(bucket) if bucket.round_number == round_number || is_full => {
+                bucket.amount = bucket
+                    .amount
+                    .checked_add(reward)
+                    .ok_or(NeobotsError::MathOverflow)?;
+            }
+            _ => user.reward_buckets.push(RewardBucket {
+                round_number,
+                amount: reward,
+            }),
+        }
+    }
+
+    emit!(RewardDistributed {
+        user: user_key,
+        amount: reward,
+        reason,
+    });
 
     Ok(())
 }
+
+// take `amount` out of the oldest reward buckets first
+pub fn consume_reward_buckets(user: &mut User, amount: u64) {
+    let mut remaining = amount;
+
+    for bucket in user.reward_buckets.iter_mut() {
+        let taken = bucket.amount.min(remaining);
+        bucket.amount -= taken;
+        remaining -= taken;
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    user.reward_buckets.retain(|bucket| bucket.amount > 0);
+}
+
+// round_max_distribution of the round `round_number`
+pub fn round_emission(
+    supply_config: &SupplyConfig,
+    round_number: u64,
+    previous_round_emission: u64,
+) -> u64 {
+    match supply_config.emission_schedule {
+        EmissionSchedule::Constant => supply_config.initial_round_emission,
+        EmissionSchedule::Halving { interval_rounds } => halving_emission(
+            supply_config.initial_round_emission,
+            round_number,
+            interval_rounds,
+        ),
+        EmissionSchedule::Decay { rate } => decay_emission(previous_round_emission, rate),
+    }
+}
+
+pub fn validate_supply_config(supply_config: &SupplyConfig) -> Result<()> {
+    match supply_config.emission_schedule {
+        EmissionSchedule::Constant => {}
+        EmissionSchedule::Halving { interval_rounds } => {
+            require!(interval_rounds > 0, NeobotsError::InvalidSupplyConfig);
+        }
+        EmissionSchedule::Decay { rate } => {
+            require!(is_valid_decay_rate(rate), NeobotsError::InvalidSupplyConfig);
+        }
+    }
+
+    Ok(())
+}
+
+// amount of the vesting schedule that is unlocked at `now` (linear release)
+pub fn vested_amount(vesting: &Vesting, now: i64) -> u64 {
+    neobots_economics::vested_amount(
+        vesting.total_amount,
+        vesting.start_time,
+        vesting.duration,
+        now,
+    )
+}
+
+// drop the rewards that were not claimed within the claim window.
+// they were never minted, so they simply stay in the emission budget.
+pub fn forfeit_expired_rewards(user_key: Pubkey, user: &mut User, forum: &Forum) -> Result<()> {
+    let claim_window_rounds = forum.round_config.claim_window_rounds;
+
+    if claim_window_rounds == 0 {
+        return Ok(());
+    }
+
+    let round_number = forum.round_status.round_number;
+    let is_expired = |bucket: &RewardBucket| {
+        is_reward_expired(bucket.round_number, claim_window_rounds, round_number)
+    };
+
+    let forfeited = user
+        .reward_buckets
+        .iter()
+        .filter(|bucket| is_expired(bucket))
+        .try_fold(0u64, |total, bucket| total.checked_add(bucket.amount))
+        .ok_or(NeobotsError::MathOverflow)?;
+    user.reward_buckets.retain(|bucket| !is_expired(bucket));
+
+    if forfeited > 0 {
+        user.claimable_amount = user
+            .claimable_amount
+            .checked_sub(forfeited)
+            .ok_or(NeobotsError::MathOverflow)?;
+
+        emit!(RewardsForfeited {
+            user: user_key,
+            amount: forfeited,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_round_config(config: &RoundConfig) -> Result<()> {
+    require!(config.round_duration > 0, NeobotsError::InvalidRoundConfig);
+    if let RoundDurationMode::Adaptive {
+        min_duration,
+        max_duration,
+        users_at_max,
+        ..
+    } = config.round_duration_mode
+    {
+        require!(
+            min_duration > 0 && min_duration <= max_duration && users_at_max > 0,
+            NeobotsError::InvalidRoundConfig
+        );
+    }
+    require!(
+        config.round_min_distribution_rate <= config.round_max_distribution_rate,
+        NeobotsError::InvalidRoundConfig
+    );
+    require!(
+        config.decay_factor <= RATIO_SCALE,
+        NeobotsError::InvalidRoundConfig
+    );
+    // every round inside the window must fit in User.reward_buckets
+    require!(
+        config.claim_window_rounds < MAX_REWARD_BUCKETS as u64,
+        NeobotsError::InvalidRoundConfig
+    );
+
+    Ok(())
+}
+
+// first round of a forum, checked against the round config it starts with
+pub fn validate_round_status(status: &RoundStatus, config: &RoundConfig) -> Result<()> {
+    require!(status.round_duration > 0, NeobotsError::InvalidRoundConfig);
+    require!(
+        status.round_distribution_rate >= config.round_min_distribution_rate
+            && status.round_distribution_rate <= config.round_max_distribution_rate,
+        NeobotsError::InvalidRoundConfig
+    );
+
+    Ok(())
+}
+
+pub fn validate_user_profile(personality: &str, name: &str, thumb: &str) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= MAX_NAME_LEN && !name.chars().any(char::is_control),
+        NeobotsError::InvalidUserProfile
+    );
+    require!(
+        personality.len() <= MAX_PERSONALITY_LEN && !personality.chars().any(char::is_control),
+        NeobotsError::InvalidUserProfile
+    );
+    // thumb is a URI or a storage key
+    require!(
+        thumb.len() <= MAX_THUMB_LEN && thumb.bytes().all(|b| b.is_ascii_graphic()),
+        NeobotsError::InvalidUserProfile
+    );
+
+    Ok(())
+}
+
+// size of the User account holding this profile
+pub fn user_account_space(personality: &str, name: &str, thumb: &str) -> usize {
+    let base_space = 8 + User::INIT_SPACE;
+
+    if personality.len() > PROFILE_FIELD_BASE_LEN
+        || name.len() > PROFILE_FIELD_BASE_LEN
+        || thumb.len() > PROFILE_FIELD_BASE_LEN
+    {
+        base_space + PROFILE_EXTENSION_SPACE
+    } else {
+        base_space
+    }
+}
+
+pub fn validate_content_uri(content_uri: &str) -> Result<()> {
+    require!(
+        content_uri.len() <= MAX_CONTENT_URI_LEN
+            && content_uri.bytes().all(|b| b.is_ascii_graphic()),
+        NeobotsError::InvalidContentUri
+    );
+
+    Ok(())
+}
+
+// the NFT changed hands since the user was bound: the operator chosen by the
+// previous owner must not keep control of the bot. returns true on mismatch.
+pub fn clear_stale_delegation(user_key: Pubkey, user: &mut User, nft_owner: Pubkey) -> bool {
+    if user.owner == nft_owner {
+        return false;
+    }
+
+    if user.operator.is_some() {
+        user.operator = None;
+
+        emit!(DelegationCleared {
+            user: user_key,
+            nft_owner,
+        });
+    }
+
+    true
+}
+
+// append a finished round, overwriting the oldest one once the buffer is full
+pub fn append_round_history(history: &mut RoundHistory, entry: RoundHistoryEntry) {
+    if history.entries.len() < ROUND_HISTORY_LEN {
+        history.entries.push(entry);
+    } else {
+        history.entries[history.next_index as usize] = entry;
+    }
+
+    history.next_index = (history.next_index + 1) % ROUND_HISTORY_LEN as u32;
+}
+
+// duration of the next round given the active users of the previous one
+pub fn next_round_duration(config: &RoundConfig, active_users: u64) -> u64 {
+    match config.round_duration_mode {
+        RoundDurationMode::Fixed => config.round_duration,
+        RoundDurationMode::Adaptive {
+            min_duration,
+            max_duration,
+            users_at_max,
+            curve,
+        } => match curve {
+            DurationCurve::Linear => {
+                linear_round_duration(min_duration, max_duration, users_at_max, active_users)
+            }
+            DurationCurve::Log => {
+                log_round_duration(min_duration, max_duration, users_at_max, active_users)
+            }
+        },
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{Forum, NeobotsError, RoundConfig};
+
+use super::validate_round_config;
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct UpdateRoundConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+        constraint = forum.admin == admin.key() @ NeobotsError::AccessDenied,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handle_update_round_config(
+    ctx: Context<UpdateRoundConfig>,
+    _forum_name: String,
+    round_config: RoundConfig,
+) -> Result<()> {
+    validate_round_config(&round_config)?;
+
+    // takes effect when the round is advanced
+    ctx.accounts.forum.next_round_config = round_config;
+
+    Ok(())
+}
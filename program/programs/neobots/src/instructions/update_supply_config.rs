@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{EmissionSchedule, Forum, NeobotsError};
+
+use super::validate_supply_config;
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct UpdateSupplyConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+        constraint = forum.admin == admin.key() @ NeobotsError::AccessDenied,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handle_update_supply_config(
+    ctx: Context<UpdateSupplyConfig>,
+    _forum_name: String,
+    max_supply: u64,
+    emission_schedule: EmissionSchedule,
+) -> Result<()> {
+    let supply_config = &mut ctx.accounts.forum.supply_config;
+
+    if supply_config.max_supply_locked && max_supply > supply_config.max_supply {
+        return Err(NeobotsError::MaxSupplyLocked.into());
+    }
+
+    supply_config.max_supply = max_supply;
+    supply_config.emission_schedule = emission_schedule;
+
+    validate_supply_config(supply_config)
+}
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct RenounceSupplyIncrease<'info> {
+    #[account(
+        mut,
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+        constraint = forum.admin == admin.key() @ NeobotsError::AccessDenied,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    pub admin: Signer<'info>,
+}
+
+// irreversible: the max supply can only be lowered afterwards
+pub fn handle_renounce_supply_increase(
+    ctx: Context<RenounceSupplyIncrease>,
+    _forum_name: String,
+) -> Result<()> {
+    ctx.accounts.forum.supply_config.max_supply_locked = true;
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    metadata::{
+        mpl_token_metadata::types::DataV2, update_metadata_accounts_v2, Metadata,
+        UpdateMetadataAccountsV2,
+    },
+    token_2022::{
+        spl_token_2022::{
+            extension::{BaseStateWithExtensions, StateWithExtensions},
+            state::Mint as MintState,
+        },
+        Token2022,
+    },
+    token_2022_extensions::{
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_update_authority, token_metadata_update_field, TokenMetadataUpdateAuthority,
+        TokenMetadataUpdateField,
+    },
+    token_interface::Mint,
+};
+
+use crate::{Forum, NeobotsError};
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct UpdateTokenMetadata<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+        has_one = mint,
+        constraint = forum.admin == admin.key() @ NeobotsError::AccessDenied,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    #[account(
+        seeds = [b"mint"],
+        bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    /// CHECK: metadata account of the mint, validated by the token metadata program
+    pub metadata: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn handle_update_token_metadata(
+    ctx: Context<UpdateTokenMetadata>,
+    _forum_name: String,
+    name: String,
+    symbol: String,
+    uri: String,
+    make_immutable: bool,
+) -> Result<()> {
+    // the mint PDA is the update authority of the metadata
+    let seeds = &["mint".as_bytes(), &[ctx.bumps.mint]];
+    let signer = [&seeds[..]];
+
+    let token_data: DataV2 = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    let metadata_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_metadata_program.to_account_info(),
+        UpdateMetadataAccountsV2 {
+            metadata: ctx.accounts.metadata.to_account_info(),
+            update_authority: ctx.accounts.mint.to_account_info(),
+        },
+        &signer,
+    );
+
+    // is_mutable can only be switched off, so `false` keeps it untouched
+    let is_mutable = if make_immutable { Some(false) } else { None };
+
+    update_metadata_accounts_v2(metadata_ctx, None, Some(token_data), None, is_mutable)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(forum_name: String)]
+pub struct UpdateTokenMetadataToken2022<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+        has_one = mint,
+        constraint = forum.admin == admin.key() @ NeobotsError::AccessDenied,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    // the metadata is stored in the mint itself (token-metadata extension)
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handle_update_token_metadata_token_2022(
+    ctx: Context<UpdateTokenMetadataToken2022>,
+    _forum_name: String,
+    name: String,
+    symbol: String,
+    uri: String,
+    make_immutable: bool,
+) -> Result<()> {
+    let mint_info = ctx.accounts.mint.to_account_info();
+
+    // the token program reallocs the mint but does not fund it,
+    // so the admin tops up the rent of the longer metadata
+    let new_data_len = {
+        let data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let current = mint_state.get_variable_len_extension::<TokenMetadata>()?;
+        let updated = TokenMetadata {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            ..current.clone()
+        };
+
+        (data.len() + updated.tlv_size_of()?).saturating_sub(current.tlv_size_of()?)
+    };
+
+    let required_lamports = Rent::get()?.minimum_balance(new_data_len);
+
+    if required_lamports > mint_info.lamports() {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            required_lamports - mint_info.lamports(),
+        )?;
+    }
+
+    let seeds = &["mint".as_bytes(), &[ctx.bumps.mint]];
+    let signer = [&seeds[..]];
+
+    for (field, value) in [
+        (Field::Name, name),
+        (Field::Symbol, symbol),
+        (Field::Uri, uri),
+    ] {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    update_authority: mint_info.clone(),
+                },
+                &signer,
+            ),
+            field,
+            value,
+        )?;
+    }
+
+    // without an update authority the metadata can never change again
+    if make_immutable {
+        token_metadata_update_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateAuthority {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    current_authority: mint_info.clone(),
+                    new_authority: mint_info.clone(),
+                },
+                &signer,
+            ),
+            OptionalNonZeroPubkey::default(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::types::UpdateAuthority;
+
+use super::{clear_stale_delegation, user_account_space, validate_user_profile};
+
+use crate::{Forum, NeobotsError, User};
+
+#[derive(Accounts)]
+#[instruction(forum_name: String, personality: String, name: String, thumb: String)]
+pub struct UpdateUserProfile<'info> {
+    #[account(
+        seeds = [b"forum", forum_name.as_bytes()],
+        bump = forum.bump,
+    )]
+    pub forum: Account<'info, Forum>,
+
+    // grows (or shrinks back) when a field crosses the base length
+    #[account(
+        mut,
+        seeds = [b"user", nft_mint.key().as_ref()],
+        bump = user.bump,
+        has_one = nft_mint,
+        realloc = user_account_space(&personality, &name, &thumb),
+        realloc::payer = owner,
+        realloc::zero = false,
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        constraint = nft_mint.owner == owner.key() @ NeobotsError::NFTNotOwned,
+        constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
+    )]
+    pub nft_mint: Account<'info, BaseAssetV1>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_user_profile(
+    ctx: Context<UpdateUserProfile>,
+    _forum_name: String,
+    personality: String,
+    name: String,
+    thumb: String,
+) -> Result<()> {
+    validate_user_profile(&personality, &name, &thumb)?;
+
+    let user = &mut ctx.accounts.user;
+    clear_stale_delegation(user.key(), user, ctx.accounts.nft_mint.owner);
+
+    user.personality = personality;
+    user.name = name;
+    user.thumb = thumb;
+
+    Ok(())
+}
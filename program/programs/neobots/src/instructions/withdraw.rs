@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
-use anchor_spl::{associated_token::AssociatedToken, token::{transfer, Transfer}, token_interface::{Mint, TokenAccount, TokenInterface}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked}};
 use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority};
 
-use crate::{Forum, NeobotsError, Operator, OperatorSession, User};
+use crate::{Forum, NeobotsError, Operator, OperatorSession, User, Withdrawn};
 
 #[derive(Accounts)]
 #[instruction(forum_name: String, operator: Pubkey, amount: u64)]
@@ -31,7 +31,7 @@ pub struct Withdraw<'info> {
 
     #[account(
         mut,
-        seeds = [b"operator", operator.key().as_ref()],
+        seeds = [b"operator", operator.authority.as_ref()],
         bump = operator.bump,
     )]
     pub operator: Account<'info, Operator>,
@@ -58,7 +58,7 @@ pub struct Withdraw<'info> {
         init_if_needed,
         payer = owner,
         associated_token::mint = mint,
-        associated_token::authority = owner,
+        associated_token::authority = operator_session,
         associated_token::token_program = token_program,
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
@@ -68,6 +68,8 @@ pub struct Withdraw<'info> {
 
     #[account(
         constraint = (nft_mint.owner == owner.key() || user.operator == Some(owner.key())) @ NeobotsError::NFTNotOwned,
+        // the deposit belongs to the bound owner, a new holder rebinds first
+        constraint = user.owner == nft_mint.owner @ NeobotsError::OwnerMismatch,
         constraint = nft_mint.update_authority == UpdateAuthority::Collection(forum.nft_collection) @ NeobotsError::NFTNotVerified,
     )]
     pub nft_mint: Account<'info, BaseAssetV1>,
@@ -94,14 +96,28 @@ pub fn handle_withdraw(
 
     let cpi = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.vault_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.user_token_account.to_account_info(),
             authority: ctx.accounts.operator_session.to_account_info(),
         },
         seeds,
     );
-    transfer(cpi, amount)?;
+    transfer_checked(cpi, amount, ctx.accounts.mint.decimals)?;
+
+    ctx.accounts.operator_session.amount_for_user = ctx
+        .accounts
+        .operator_session
+        .amount_for_user
+        .checked_sub(amount)
+        .ok_or(NeobotsError::MathOverflow)?;
+
+    emit!(Withdrawn {
+        user: binding,
+        operator_session: ctx.accounts.operator_session.key(),
+        amount,
+    });
 
     Ok(())
 }
@@ -1,10 +1,12 @@
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod states;
 
 use anchor_lang::prelude::*;
 
 pub use error::*;
+pub use events::*;
 pub use instructions::*;
 pub use states::*;
 
@@ -14,8 +16,31 @@ declare_id!("GRF2iKi1XR6NffdV6dRfX1m9PmjcrjxVxHFjZ9MgzycG");
 pub mod neobots {
     use super::*;
 
-    pub fn initialize_forum(ctx: Context<InitializeForum>, forum_name: String) -> Result<()> {
-        handle_initialize_forum(ctx, forum_name)
+    pub fn initialize_forum(
+        ctx: Context<InitializeForum>,
+        forum_name: String,
+        round_config: Option<RoundConfig>,
+        round_status: Option<RoundStatus>,
+    ) -> Result<()> {
+        handle_initialize_forum(ctx, forum_name, round_config, round_status)
+    }
+
+    pub fn initialize_forum_token_2022(
+        ctx: Context<InitializeForumToken2022>,
+        forum_name: String,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+        round_config: Option<RoundConfig>,
+        round_status: Option<RoundStatus>,
+    ) -> Result<()> {
+        handle_initialize_forum_token_2022(
+            ctx,
+            forum_name,
+            transfer_fee_basis_points,
+            maximum_fee,
+            round_config,
+            round_status,
+        )
     }
 
     pub fn initialize_user(
@@ -28,6 +53,24 @@ pub mod neobots {
         handle_initialize_user(ctx, forum_name, personality, name, thumb)
     }
 
+    pub fn update_user_profile(
+        ctx: Context<UpdateUserProfile>,
+        forum_name: String,
+        personality: String,
+        name: String,
+        thumb: String,
+    ) -> Result<()> {
+        handle_update_user_profile(ctx, forum_name, personality, name, thumb)
+    }
+
+    pub fn close_user(ctx: Context<CloseUser>, forum_name: String) -> Result<()> {
+        handle_close_user(ctx, forum_name)
+    }
+
+    pub fn rebind_owner(ctx: Context<RebindOwner>, forum_name: String) -> Result<()> {
+        handle_rebind_owner(ctx, forum_name)
+    }
+
     pub fn set_user_operator(ctx: Context<SetUserOperator>, forum_name: String) -> Result<()> {
         handle_set_user_operator(ctx, forum_name)
     }
@@ -48,8 +91,10 @@ pub mod neobots {
         forum_name: String,
         content: String,
         tag_name: String,
+        content_hash: [u8; 32],
+        content_uri: String,
     ) -> Result<()> {
-        handle_create_post(ctx, forum_name, content, tag_name)
+        handle_create_post(ctx, forum_name, content, tag_name, content_hash, content_uri)
     }
 
     pub fn add_comment(
@@ -57,8 +102,10 @@ pub mod neobots {
         forum_name: String,
         post_sequence: u32,
         content: String,
+        content_hash: [u8; 32],
+        content_uri: String,
     ) -> Result<()> {
-        handle_add_comment(ctx, forum_name, post_sequence, content)
+        handle_add_comment(ctx, forum_name, post_sequence, content, content_hash, content_uri)
     }
 
     pub fn add_reaction(
@@ -85,6 +132,67 @@ pub mod neobots {
         handle_claim(ctx, forum_id)
     }
 
+    pub fn release_vested(ctx: Context<ReleaseVested>, forum_name: String) -> Result<()> {
+        handle_release_vested(ctx, forum_name)
+    }
+
+    pub fn expire_rewards(ctx: Context<ExpireRewards>, forum_name: String) -> Result<()> {
+        handle_expire_rewards(ctx, forum_name)
+    }
+
+    pub fn update_round_config(
+        ctx: Context<UpdateRoundConfig>,
+        forum_name: String,
+        round_config: RoundConfig,
+    ) -> Result<()> {
+        handle_update_round_config(ctx, forum_name, round_config)
+    }
+
+    pub fn update_supply_config(
+        ctx: Context<UpdateSupplyConfig>,
+        forum_name: String,
+        max_supply: u64,
+        emission_schedule: EmissionSchedule,
+    ) -> Result<()> {
+        handle_update_supply_config(ctx, forum_name, max_supply, emission_schedule)
+    }
+
+    pub fn renounce_supply_increase(
+        ctx: Context<RenounceSupplyIncrease>,
+        forum_name: String,
+    ) -> Result<()> {
+        handle_renounce_supply_increase(ctx, forum_name)
+    }
+
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadata>,
+        forum_name: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        make_immutable: bool,
+    ) -> Result<()> {
+        handle_update_token_metadata(ctx, forum_name, name, symbol, uri, make_immutable)
+    }
+
+    pub fn update_token_metadata_token_2022(
+        ctx: Context<UpdateTokenMetadataToken2022>,
+        forum_name: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        make_immutable: bool,
+    ) -> Result<()> {
+        handle_update_token_metadata_token_2022(ctx, forum_name, name, symbol, uri, make_immutable)
+    }
+
+    pub fn harvest_transfer_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+        forum_name: String,
+    ) -> Result<()> {
+        handle_harvest_transfer_fees(ctx, forum_name)
+    }
+
     // operator instructions
     pub fn initialize_operator_pool(ctx: Context<InitializeOperatorPool>, forum_name: String) -> Result<()> {
         handle_initialize_operator_pool(ctx, forum_name)
@@ -141,7 +249,20 @@ pub mod neobots {
         forum_name: String,
         post_sequence: u32,
         content: String,
+        content_hash: [u8; 32],
+        content_uri: String,
     ) -> Result<()> {
-        handle_operator_add_comment(ctx, forum_name, post_sequence, content)
+        handle_operator_add_comment(
+            ctx,
+            forum_name,
+            post_sequence,
+            content,
+            content_hash,
+            content_uri,
+        )
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>, forum_name: String) -> Result<()> {
+        handle_migrate_account(ctx, forum_name)
     }
 }
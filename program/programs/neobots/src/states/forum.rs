@@ -26,8 +26,23 @@ pub struct Forum {
     // this values will be copied to round_config when round is reset
     pub next_round_config: RoundConfig,
 
+    // hard cap and emission schedule of the reward token
+    pub supply_config: SupplyConfig,
+
+    // activity of the current round, reset when round is reset
+    pub round_activity: RoundActivity,
+
+    // activity of the previous round
+    pub last_round_activity: RoundActivity,
+
+    // slot of the last round reset, a round can be advanced once per slot
+    pub last_advance_slot: u64,
+
     // bump
     pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
 }
 
 // values that are updated when round is reset
@@ -41,12 +56,14 @@ pub struct RoundStatus {
     pub round_max_distribution: u64,
     // rate of distribution to adjust the amount of points can be distributed
     pub round_distribution_rate: u64,
+    // duration of this round in seconds, decided when round is reset
+    pub round_duration: u64,
 }
 
 // values that are manually updatable
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct RoundConfig {
-    // duration of the round in seconds
+    // duration of the round in seconds (used as is in Fixed mode)
     pub round_duration: u64,
     // this parameter is used to decide round_distribution_rate
     pub round_min_distribution_rate: u64,
@@ -63,6 +80,75 @@ pub struct RoundConfig {
     // decay factor for the repeated reaction
     pub decay_factor: u64,
 
+    // how the duration of the next round is decided
+    pub round_duration_mode: RoundDurationMode,
+
     // default action points (AP) for users when the round is reset
     pub default_action_points: ActionPoints,
+
+    // length in seconds over which claimed rewards are released (0 = instant)
+    pub vesting_duration: u64,
+
+    // number of rounds after which unclaimed rewards are forfeited (0 = never)
+    pub claim_window_rounds: u64,
+
+    // amount minted to whoever advances the round (0 = no reward)
+    pub crank_reward: u64,
+}
+
+// number of active users and actions in a round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct RoundActivity {
+    // distinct users that performed at least one action
+    pub active_users: u64,
+    pub post_count: u64,
+    pub comment_count: u64,
+    pub upvote_count: u64,
+    pub downvote_count: u64,
+    pub like_count: u64,
+    pub banvote_count: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RoundDurationMode {
+    // every round lasts round_duration
+    Fixed,
+    // duration grows with the active users of the previous round, from
+    // `min_duration` (no activity) to `max_duration` (`users_at_max` or more)
+    Adaptive {
+        min_duration: u64,
+        max_duration: u64,
+        users_at_max: u64,
+        curve: DurationCurve,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DurationCurve {
+    Linear,
+    // grows fast for the first users, then flattens
+    Log,
+}
+
+// values that bound the total amount of minted tokens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SupplyConfig {
+    // mint.supply can never exceed this amount
+    pub max_supply: u64,
+    // once set, max_supply can only be lowered
+    pub max_supply_locked: bool,
+    // round_max_distribution of the first round
+    pub initial_round_emission: u64,
+    // how round_max_distribution evolves when the round is advanced
+    pub emission_schedule: EmissionSchedule,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum EmissionSchedule {
+    // same emission every round
+    Constant,
+    // emission is halved every `interval_rounds` rounds
+    Halving { interval_rounds: u64 },
+    // emission decreases by `rate` / RATIO_SCALE every round
+    Decay { rate: u64 },
 }
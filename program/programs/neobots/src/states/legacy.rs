@@ -0,0 +1,268 @@
+//! Layouts of the accounts created before the `version` field, as the first
+//! release of the program serialized them. The fields added since then get an
+//! explicit default when an account is upgraded.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    ActionPoints, Forum, InteractionMetricEntry, Operator, OperatorPool, OperatorPrice,
+    OperatorSession, Post, RoundActivity, RoundConfig, RoundDurationMode, RoundStatus,
+    SupplyConfig, User, UserCounter, ACCOUNT_VERSION, INITIAL_SUPPLY_CONFIG,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoundStatusV0 {
+    pub round_number: u64,
+    pub round_start_time: i64,
+    pub round_max_distribution: u64,
+    pub round_distribution_rate: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoundConfigV0 {
+    pub round_duration: u64,
+    pub round_min_distribution_rate: u64,
+    pub round_max_distribution_rate: u64,
+    pub k_comment_receiver: u64,
+    pub k_comment: u64,
+    pub k_quote: u64,
+    pub k_reaction_giver: u64,
+    pub k_reaction_receiver: u64,
+    pub decay_factor: u64,
+    pub default_action_points: ActionPoints,
+}
+
+impl From<RoundConfigV0> for RoundConfig {
+    fn from(config: RoundConfigV0) -> Self {
+        Self {
+            round_duration: config.round_duration,
+            round_min_distribution_rate: config.round_min_distribution_rate,
+            round_max_distribution_rate: config.round_max_distribution_rate,
+            k_comment_receiver: config.k_comment_receiver,
+            k_comment: config.k_comment,
+            k_quote: config.k_quote,
+            k_reaction_giver: config.k_reaction_giver,
+            k_reaction_receiver: config.k_reaction_receiver,
+            decay_factor: config.decay_factor,
+            round_duration_mode: RoundDurationMode::Fixed,
+            default_action_points: config.default_action_points,
+            // claims were minted right away, never expired and nobody was
+            // paid to advance the round
+            vesting_duration: 0,
+            claim_window_rounds: 0,
+            crank_reward: 0,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ForumV0 {
+    pub admin: Pubkey,
+    pub round_distributed: u64,
+    pub mint: Pubkey,
+    pub nft_collection: Pubkey,
+    pub round_status: RoundStatusV0,
+    pub round_config: RoundConfigV0,
+    pub next_round_config: RoundConfigV0,
+    pub bump: u8,
+}
+
+impl From<ForumV0> for Forum {
+    fn from(forum: ForumV0) -> Self {
+        let round_status = forum.round_status;
+
+        Self {
+            admin: forum.admin,
+            round_distributed: forum.round_distributed,
+            mint: forum.mint,
+            nft_collection: forum.nft_collection,
+            round_status: RoundStatus {
+                round_number: round_status.round_number,
+                round_start_time: round_status.round_start_time,
+                round_max_distribution: round_status.round_max_distribution,
+                round_distribution_rate: round_status.round_distribution_rate,
+                // rounds had the fixed duration of the config
+                round_duration: forum.round_config.round_duration,
+            },
+            round_config: forum.round_config.into(),
+            next_round_config: forum.next_round_config.into(),
+            // the initial supply config, emitting what the current round does
+            supply_config: SupplyConfig {
+                initial_round_emission: round_status.round_max_distribution,
+                ..INITIAL_SUPPLY_CONFIG
+            },
+            // activity was not tracked
+            round_activity: RoundActivity::default(),
+            last_round_activity: RoundActivity::default(),
+            last_advance_slot: 0,
+            bump: forum.bump,
+            version: ACCOUNT_VERSION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserV0 {
+    pub nft_mint: Pubkey,
+    pub claimable_amount: u64,
+    pub local_round_number: u64,
+    pub action_points: ActionPoints,
+    pub interaction_metrics: Vec<InteractionMetricEntry>,
+    pub post_count: u32,
+    pub comment_count: u32,
+    pub upvote_count: u32,
+    pub downvote_count: u32,
+    pub like_count: u32,
+    pub banvote_count: u32,
+    pub reaction_count: u32,
+    pub received_upvote_count: u64,
+    pub received_downvote_count: u64,
+    pub received_like_count: u64,
+    pub received_banvote_count: u64,
+    pub received_reaction_count: u64,
+    pub received_comment_count: u64,
+    pub personality: String,
+    pub name: String,
+    pub thumb: String,
+    pub operator: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl From<UserV0> for User {
+    fn from(user: UserV0) -> Self {
+        Self {
+            nft_mint: user.nft_mint,
+            // unknown, migrate_account sets the holder of the NFT
+            owner: Pubkey::default(),
+            claimable_amount: user.claimable_amount,
+            local_round_number: user.local_round_number,
+            last_active_round: None,
+            // the claimable amount is not bucketed, it never expires
+            reward_buckets: Vec::new(),
+            action_points: user.action_points,
+            interaction_metrics: user.interaction_metrics,
+            post_count: user.post_count,
+            comment_count: user.comment_count,
+            upvote_count: user.upvote_count,
+            downvote_count: user.downvote_count,
+            like_count: user.like_count,
+            banvote_count: user.banvote_count,
+            reaction_count: user.reaction_count,
+            received_upvote_count: user.received_upvote_count,
+            received_downvote_count: user.received_downvote_count,
+            received_like_count: user.received_like_count,
+            received_banvote_count: user.received_banvote_count,
+            received_reaction_count: user.received_reaction_count,
+            received_comment_count: user.received_comment_count,
+            personality: user.personality,
+            name: user.name,
+            thumb: user.thumb,
+            operator: user.operator,
+            bump: user.bump,
+            version: ACCOUNT_VERSION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PostV0 {
+    pub author: Pubkey,
+    pub created_at: i64,
+    pub sequence: u32,
+    pub interactable: bool,
+    pub content: String,
+    pub bump: u8,
+}
+
+impl From<PostV0> for Post {
+    fn from(post: PostV0) -> Self {
+        Self {
+            author: post.author,
+            created_at: post.created_at,
+            sequence: post.sequence,
+            interactable: post.interactable,
+            content: post.content,
+            // the content was stored inline, without commitment
+            content_hash: [0; 32],
+            content_uri: String::new(),
+            bump: post.bump,
+            version: ACCOUNT_VERSION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OperatorV0 {
+    pub authority: Pubkey,
+    pub price: OperatorPrice,
+    pub next_round_price: OperatorPrice,
+    pub bump: u8,
+}
+
+impl From<OperatorV0> for Operator {
+    fn from(operator: OperatorV0) -> Self {
+        Self {
+            authority: operator.authority,
+            price: operator.price,
+            next_round_price: operator.next_round_price,
+            bump: operator.bump,
+            version: ACCOUNT_VERSION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OperatorSessionV0 {
+    pub user: Pubkey,
+    pub operator: Pubkey,
+    pub vault: Pubkey,
+    pub amount_for_user: u64,
+    pub amount_for_operator: u64,
+    pub bump: u8,
+}
+
+impl From<OperatorSessionV0> for OperatorSession {
+    fn from(session: OperatorSessionV0) -> Self {
+        Self {
+            user: session.user,
+            operator: session.operator,
+            vault: session.vault,
+            amount_for_user: session.amount_for_user,
+            amount_for_operator: session.amount_for_operator,
+            bump: session.bump,
+            version: ACCOUNT_VERSION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserCounterV0 {
+    pub count: u64,
+    pub bump: u8,
+}
+
+impl From<UserCounterV0> for UserCounter {
+    fn from(counter: UserCounterV0) -> Self {
+        Self {
+            count: counter.count,
+            bump: counter.bump,
+            version: ACCOUNT_VERSION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OperatorPoolV0 {
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+impl From<OperatorPoolV0> for OperatorPool {
+    fn from(pool: OperatorPoolV0) -> Self {
+        Self {
+            authority: pool.authority,
+            bump: pool.bump,
+            version: ACCOUNT_VERSION,
+        }
+    }
+}
@@ -23,4 +23,13 @@ pub mod operatorpool;
 pub use operatorpool::*;
 
 pub mod operatorsession;
-pub use operatorsession::*;
\ No newline at end of file
+pub use operatorsession::*;
+
+pub mod vesting;
+pub use vesting::*;
+
+pub mod roundhistory;
+pub use roundhistory::*;
+
+pub mod legacy;
+pub use legacy::*;
@@ -16,4 +16,7 @@ pub struct Operator {
 
     // bump
     pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
 }
\ No newline at end of file
@@ -8,4 +8,7 @@ pub struct OperatorPool {
 
     // bump
     pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
 }
\ No newline at end of file
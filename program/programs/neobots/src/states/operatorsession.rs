@@ -10,4 +10,7 @@ pub struct OperatorSession {
     pub amount_for_operator: u64,
     // bump
     pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::MAX_CONTENT_URI_LEN;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Post {
@@ -10,5 +12,14 @@ pub struct Post {
     #[max_len(30)]
     pub content: String,
 
+    // sha256 of the full body kept in the content store
+    pub content_hash: [u8; 32],
+    // key or URI of the body in the content store
+    #[max_len(MAX_CONTENT_URI_LEN)]
+    pub content_uri: String,
+
     pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
 }
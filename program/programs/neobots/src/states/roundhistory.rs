@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{RoundActivity, ROUND_HISTORY_LEN};
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoundHistory {
+    // forum whose rounds are recorded
+    pub forum: Pubkey,
+
+    // slot of `entries` written on the next advance once the buffer is full
+    pub next_index: u32,
+
+    // last ROUND_HISTORY_LEN finished rounds, oldest at `next_index` once full
+    #[max_len(ROUND_HISTORY_LEN)]
+    pub entries: Vec<RoundHistoryEntry>,
+
+    // bump
+    pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RoundHistoryEntry {
+    pub round_number: u64,
+    pub round_start_time: i64,
+    pub round_end_time: i64,
+    pub round_distribution_rate: u64,
+    pub round_max_distribution: u64,
+    pub round_distributed: u64,
+    pub activity: RoundActivity,
+}
@@ -1,13 +1,22 @@
 use anchor_lang::prelude::*;
 
-use crate::ActionPoints;
+use crate::{ActionPoints, MAX_REWARD_BUCKETS};
 
 #[account]
 #[derive(InitSpace)]
 pub struct User {
     pub nft_mint: Pubkey,
+    // owner of the NFT when the user was bound, the operator is only
+    // trusted while the NFT stays with this owner
+    pub owner: Pubkey,
     pub claimable_amount: u64,
     pub local_round_number: u64,
+    // last round in which the user performed an action
+    pub last_active_round: Option<u64>,
+
+    // rewards earned per round, used to forfeit rewards left unclaimed
+    #[max_len(MAX_REWARD_BUCKETS)]
+    pub reward_buckets: Vec<RewardBucket>,
 
     // Action points (AP) for users
     pub action_points: ActionPoints,
@@ -40,6 +49,7 @@ pub struct User {
     pub received_comment_count: u64,
 
     // user profile data
+    // (up to PROFILE_FIELD_BASE_LEN, longer values use the profile extension space)
     #[max_len(30)]
     pub personality: String,
 
@@ -52,6 +62,9 @@ pub struct User {
     pub operator: Option<Pubkey>,
 
     pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -59,3 +72,9 @@ pub struct InteractionMetricEntry {
     pub short_user_id: [u8; 6],
     pub count: u8,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RewardBucket {
+    pub round_number: u64,
+    pub amount: u64,
+}
@@ -5,4 +5,7 @@ use anchor_lang::prelude::*;
 pub struct UserCounter {
     pub count: u64,
     pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
 }
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vesting {
+    // bot NFT whose rewards are vested
+    pub nft_mint: Pubkey,
+
+    // amount covered by the current schedule
+    pub total_amount: u64,
+
+    // amount of the current schedule already sent to the holder
+    pub released_amount: u64,
+
+    // timestamp when the current schedule started
+    pub start_time: i64,
+
+    // length of the current schedule in seconds
+    pub duration: u64,
+
+    // bump
+    pub bump: u8,
+
+    // layout version, see migrate_account
+    pub version: u8,
+}
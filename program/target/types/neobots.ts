@@ -28,6 +28,7 @@ export type Neobots = {
       "accounts": [
         {
           "name": "forum",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -77,7 +78,8 @@ export type Neobots = {
           }
         },
         {
-          "name": "postAuthor"
+          "name": "postAuthor",
+          "writable": true
         },
         {
           "name": "senderUser",
@@ -125,6 +127,19 @@ export type Neobots = {
         {
           "name": "content",
           "type": "string"
+        },
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "contentUri",
+          "type": "string"
         }
       ]
     },
@@ -143,6 +158,7 @@ export type Neobots = {
       "accounts": [
         {
           "name": "forum",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -312,11 +328,124 @@ export type Neobots = {
             ]
           }
         },
+        {
+          "name": "roundHistory",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  110,
+                  100,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "forum"
+              }
+            ]
+          }
+        },
         {
           "name": "signer",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "signerTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          },
+          "relations": [
+            "forum"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -453,6 +582,89 @@ export type Neobots = {
             }
           }
         },
+        {
+          "name": "vesting",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vesting"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
         {
           "name": "mint",
           "writable": true,
@@ -493,16 +705,16 @@ export type Neobots = {
       ]
     },
     {
-      "name": "createPost",
+      "name": "closeUser",
       "discriminator": [
-        123,
-        92,
-        184,
-        29,
-        231,
+        86,
+        219,
+        138,
+        140,
+        236,
         24,
-        15,
-        202
+        118,
+        200
       ],
       "accounts": [
         {
@@ -527,25 +739,25 @@ export type Neobots = {
           }
         },
         {
-          "name": "tag",
+          "name": "userCounter",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  99,
+                  111,
+                  117,
+                  110,
                   116,
-                  97,
-                  103
+                  101,
+                  114
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "forum"
-              },
-              {
-                "kind": "arg",
-                "path": "tagName"
               }
             ]
           }
@@ -572,37 +784,191 @@ export type Neobots = {
           }
         },
         {
-          "name": "post",
+          "name": "operatorSession",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  111,
                   112,
+                  101,
+                  114,
+                  97,
+                  116,
                   111,
+                  114,
                   115,
-                  116
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
                 ]
               },
-              {
-                "kind": "account",
-                "path": "forum"
-              },
               {
                 "kind": "account",
                 "path": "user"
-              },
-              {
-                "kind": "account",
-                "path": "user.post_count",
-                "account": "user"
               }
             ]
           }
         },
         {
-          "name": "owner",
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "ownerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "nftMint",
+          "relations": [
+            "user"
+          ]
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "createPost",
+      "discriminator": [
+        123,
+        92,
+        184,
+        29,
+        231,
+        24,
+        15,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tag",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "forum"
+              },
+              {
+                "kind": "arg",
+                "path": "tagName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "post",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "forum"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "user.post_count",
+                "account": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
           "writable": true,
           "signer": true
         },
@@ -629,6 +995,19 @@ export type Neobots = {
         {
           "name": "tagName",
           "type": "string"
+        },
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "contentUri",
+          "type": "string"
         }
       ]
     },
@@ -723,7 +1102,7 @@ export type Neobots = {
               },
               {
                 "kind": "arg",
-                "path": "operator"
+                "path": "operator.authority"
               }
             ]
           }
@@ -858,7 +1237,7 @@ export type Neobots = {
             "seeds": [
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "operatorSession"
               },
               {
                 "kind": "account",
@@ -947,21 +1326,20 @@ export type Neobots = {
       ]
     },
     {
-      "name": "initializeForum",
+      "name": "expireRewards",
       "discriminator": [
-        122,
-        74,
+        249,
+        29,
+        95,
         123,
-        255,
-        83,
-        3,
-        86,
-        24
+        170,
+        90,
+        76,
+        180
       ],
       "accounts": [
         {
           "name": "forum",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -982,7 +1360,7 @@ export type Neobots = {
           }
         },
         {
-          "name": "userCounter",
+          "name": "user",
           "writable": true,
           "pda": {
             "seeds": [
@@ -992,62 +1370,20 @@ export type Neobots = {
                   117,
                   115,
                   101,
-                  114,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116,
-                  101,
                   114
                 ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "mint",
-          "writable": true,
-          "pda": {
-            "seeds": [
+              },
               {
-                "kind": "const",
-                "value": [
-                  109,
-                  105,
-                  110,
-                  116
-                ]
+                "kind": "account",
+                "path": "user.nft_mint",
+                "account": "user"
               }
             ]
           }
         },
         {
-          "name": "metadata",
-          "writable": true
-        },
-        {
-          "name": "nftCollection"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
-        },
-        {
-          "name": "tokenProgram"
+          "name": "signer",
+          "signer": true
         }
       ],
       "args": [
@@ -1058,16 +1394,16 @@ export type Neobots = {
       ]
     },
     {
-      "name": "initializeOperator",
+      "name": "harvestTransferFees",
       "discriminator": [
-        155,
-        33,
-        216,
-        254,
-        233,
-        227,
-        175,
-        212
+        246,
+        104,
+        231,
+        171,
+        131,
+        105,
+        50,
+        55
       ],
       "accounts": [
         {
@@ -1092,106 +1428,118 @@ export type Neobots = {
           }
         },
         {
-          "name": "operatorPool",
+          "name": "mint",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
-                  112,
-                  101,
-                  114,
-                  97,
-                  116,
-                  111,
-                  114,
-                  112,
-                  111,
-                  111,
-                  108
+                  109,
+                  105,
+                  110,
+                  116
                 ]
               }
             ]
-          }
-        },
+          },
+          "relations": [
+            "forum"
+          ]
+        },
         {
-          "name": "operator",
+          "name": "treasuryTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "value": [
-                  111,
-                  112,
-                  101,
-                  114,
-                  97,
-                  116,
-                  111,
-                  114
-                ]
+                "kind": "account",
+                "path": "forum.admin",
+                "account": "forum"
               },
               {
                 "kind": "account",
-                "path": "payer"
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          "name": "payer",
-          "writable": true,
+          "name": "signer",
           "signer": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
       "args": [
         {
           "name": "forumName",
           "type": "string"
-        },
-        {
-          "name": "operatorName",
-          "type": "string"
-        },
-        {
-          "name": "pricePerPost",
-          "type": "u64"
-        },
-        {
-          "name": "pricePerComment",
-          "type": "u64"
-        },
-        {
-          "name": "pricePerLike",
-          "type": "u64"
-        },
-        {
-          "name": "pricePerVote",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "initializeOperatorPool",
+      "name": "initializeForum",
       "discriminator": [
-        90,
-        203,
-        214,
-        227,
-        204,
-        96,
-        190,
-        106
+        122,
+        74,
+        123,
+        255,
+        83,
+        3,
+        86,
+        24
       ],
       "accounts": [
         {
           "name": "forum",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1212,25 +1560,24 @@ export type Neobots = {
           }
         },
         {
-          "name": "operatorPool",
+          "name": "userCounter",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
-                  112,
+                  117,
+                  115,
                   101,
                   114,
-                  97,
-                  116,
-                  111,
-                  114,
-                  112,
-                  111,
+                  99,
                   111,
-                  108
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
                 ]
               }
             ]
@@ -1241,33 +1588,89 @@ export type Neobots = {
           "writable": true,
           "signer": true
         },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true
+        },
+        {
+          "name": "nftCollection"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "tokenProgram"
         }
       ],
       "args": [
         {
           "name": "forumName",
           "type": "string"
+        },
+        {
+          "name": "roundConfig",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "roundConfig"
+              }
+            }
+          }
+        },
+        {
+          "name": "roundStatus",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "roundStatus"
+              }
+            }
+          }
         }
       ]
     },
     {
-      "name": "initializeOperatorSession",
+      "name": "initializeForumToken2022",
       "discriminator": [
-        121,
-        147,
-        197,
-        57,
-        120,
-        253,
-        189,
-        83
+        245,
+        216,
+        188,
+        138,
+        255,
+        209,
+        107,
+        62
       ],
       "accounts": [
         {
           "name": "forum",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1288,23 +1691,7 @@ export type Neobots = {
           }
         },
         {
-          "name": "mint",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  109,
-                  105,
-                  110,
-                  116
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "user",
+          "name": "userCounter",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1314,126 +1701,50 @@ export type Neobots = {
                   117,
                   115,
                   101,
+                  114,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
                   114
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "nftMint"
               }
             ]
           }
         },
         {
-          "name": "operatorSession",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
-                  112,
-                  101,
-                  114,
-                  97,
-                  116,
-                  111,
-                  114,
-                  115,
-                  101,
-                  115,
-                  115,
+                  109,
                   105,
-                  111,
-                  110
+                  110,
+                  116
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "user"
               }
             ]
           }
         },
         {
-          "name": "vaultTokenAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "owner"
-              },
-              {
-                "kind": "account",
-                "path": "tokenProgram"
-              },
-              {
-                "kind": "account",
-                "path": "mint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
-        },
-        {
-          "name": "owner",
-          "writable": true,
-          "signer": true
+          "name": "nftCollection"
         },
         {
-          "name": "nftMint",
-          "relations": [
-            "user"
-          ]
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
           "name": "tokenProgram"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
@@ -1442,22 +1753,46 @@ export type Neobots = {
           "type": "string"
         },
         {
-          "name": "operator",
-          "type": "pubkey"
+          "name": "transferFeeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "maximumFee",
+          "type": "u64"
+        },
+        {
+          "name": "roundConfig",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "roundConfig"
+              }
+            }
+          }
+        },
+        {
+          "name": "roundStatus",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "roundStatus"
+              }
+            }
+          }
         }
       ]
     },
     {
-      "name": "initializeUser",
+      "name": "initializeOperator",
       "discriminator": [
-        111,
-        17,
-        185,
-        250,
-        60,
-        122,
-        38,
-        254
+        155,
+        33,
+        216,
+        254,
+        233,
+        227,
+        175,
+        212
       ],
       "accounts": [
         {
@@ -1482,53 +1817,54 @@ export type Neobots = {
           }
         },
         {
-          "name": "userCounter",
-          "writable": true,
+          "name": "operatorPool",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
+                  111,
+                  112,
                   101,
                   114,
-                  99,
-                  111,
-                  117,
-                  110,
+                  97,
                   116,
-                  101,
-                  114
+                  111,
+                  114,
+                  112,
+                  111,
+                  111,
+                  108
                 ]
               }
             ]
           }
         },
         {
-          "name": "user",
+          "name": "operator",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
+                  111,
+                  112,
                   101,
+                  114,
+                  97,
+                  116,
+                  111,
                   114
                 ]
               },
               {
                 "kind": "account",
-                "path": "nftMint"
+                "path": "payer"
               }
             ]
           }
         },
-        {
-          "name": "nftMint"
-        },
         {
           "name": "payer",
           "writable": true,
@@ -1545,30 +1881,38 @@ export type Neobots = {
           "type": "string"
         },
         {
-          "name": "personality",
+          "name": "operatorName",
           "type": "string"
         },
         {
-          "name": "name",
-          "type": "string"
+          "name": "pricePerPost",
+          "type": "u64"
         },
         {
-          "name": "thumb",
-          "type": "string"
+          "name": "pricePerComment",
+          "type": "u64"
+        },
+        {
+          "name": "pricePerLike",
+          "type": "u64"
+        },
+        {
+          "name": "pricePerVote",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "operatorAddComment",
+      "name": "initializeOperatorPool",
       "discriminator": [
-        230,
-        120,
-        153,
-        149,
+        90,
+        203,
+        214,
+        227,
+        204,
+        96,
         190,
-        144,
-        187,
-        252
+        106
       ],
       "accounts": [
         {
@@ -1593,81 +1937,114 @@ export type Neobots = {
           }
         },
         {
-          "name": "post",
+          "name": "operatorPool",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  111,
                   112,
+                  101,
+                  114,
+                  97,
+                  116,
                   111,
-                  115,
-                  116
+                  114,
+                  112,
+                  111,
+                  111,
+                  108
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "forum"
-              },
-              {
-                "kind": "account",
-                "path": "postAuthor"
-              },
-              {
-                "kind": "arg",
-                "path": "postSequence"
               }
             ]
           }
         },
         {
-          "name": "postAuthor"
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "senderUser",
-          "writable": true,
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initializeOperatorSession",
+      "discriminator": [
+        121,
+        147,
+        197,
+        57,
+        120,
+        253,
+        189,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "forum",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  102,
+                  111,
+                  114,
                   117,
-                  115,
-                  101,
-                  114
+                  109
                 ]
               },
               {
-                "kind": "account",
-                "path": "senderNftMint"
+                "kind": "arg",
+                "path": "forumName"
               }
             ]
           }
         },
         {
-          "name": "senderNftMint"
-        },
-        {
-          "name": "operator",
+          "name": "mint",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
-                  112,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
                   101,
-                  114,
-                  97,
-                  116,
-                  111,
                   114
                 ]
               },
               {
                 "kind": "account",
-                "path": "operator"
+                "path": "nftMint"
               }
             ]
           }
@@ -1699,16 +2076,86 @@ export type Neobots = {
               },
               {
                 "kind": "account",
-                "path": "senderUser"
+                "path": "user"
               }
             ]
           }
         },
         {
-          "name": "sender",
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "operatorSession"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "owner",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "nftMint",
+          "relations": [
+            "user"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -1720,26 +2167,22 @@ export type Neobots = {
           "type": "string"
         },
         {
-          "name": "postSequence",
-          "type": "u32"
-        },
-        {
-          "name": "content",
-          "type": "string"
+          "name": "operator",
+          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "resetUserActionPoints",
+      "name": "initializeUser",
       "discriminator": [
-        65,
-        202,
-        184,
-        4,
-        30,
-        134,
-        0,
-        181
+        111,
+        17,
+        185,
+        250,
+        60,
+        122,
+        38,
+        254
       ],
       "accounts": [
         {
@@ -1763,6 +2206,30 @@ export type Neobots = {
             ]
           }
         },
+        {
+          "name": "userCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "user",
           "writable": true,
@@ -1788,7 +2255,7 @@ export type Neobots = {
           "name": "nftMint"
         },
         {
-          "name": "signer",
+          "name": "payer",
           "writable": true,
           "signer": true
         },
@@ -1801,20 +2268,32 @@ export type Neobots = {
         {
           "name": "forumName",
           "type": "string"
+        },
+        {
+          "name": "personality",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "thumb",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "setOperator",
+      "name": "migrateAccount",
       "discriminator": [
-        238,
-        153,
-        101,
-        169,
-        243,
-        131,
-        36,
-        1
+        177,
+        228,
+        60,
+        125,
+        13,
+        116,
+        44,
+        84
       ],
       "accounts": [
         {
@@ -1838,174 +2317,186 @@ export type Neobots = {
             ]
           }
         },
+        {
+          "name": "account",
+          "writable": true
+        },
         {
           "name": "user",
+          "optional": true
+        },
+        {
+          "name": "nftMint",
+          "optional": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "operatorAddComment",
+      "discriminator": [
+        230,
+        120,
+        153,
+        149,
+        190,
+        144,
+        187,
+        252
+      ],
+      "accounts": [
+        {
+          "name": "forum",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  102,
+                  111,
+                  114,
                   117,
-                  115,
-                  101,
-                  114
+                  109
                 ]
               },
               {
-                "kind": "account",
-                "path": "nftMint"
+                "kind": "arg",
+                "path": "forumName"
               }
             ]
           }
         },
         {
-          "name": "operator",
+          "name": "post",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
                   112,
-                  101,
-                  114,
-                  97,
-                  116,
                   111,
-                  114
+                  115,
+                  116
                 ]
               },
+              {
+                "kind": "account",
+                "path": "forum"
+              },
+              {
+                "kind": "account",
+                "path": "postAuthor"
+              },
               {
                 "kind": "arg",
-                "path": "operator"
+                "path": "postSequence"
               }
             ]
           }
         },
         {
-          "name": "operatorSession",
+          "name": "postAuthor",
+          "writable": true
+        },
+        {
+          "name": "senderUser",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
-                  112,
-                  101,
-                  114,
-                  97,
-                  116,
-                  111,
-                  114,
+                  117,
                   115,
                   101,
-                  115,
-                  115,
-                  105,
-                  111,
-                  110
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "user"
+                "path": "senderNftMint"
               }
             ]
           }
         },
         {
-          "name": "owner",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "nftMint",
-          "relations": [
-            "user"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "forumName",
-          "type": "string"
+          "name": "senderNftMint"
         },
         {
           "name": "operator",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "setUserOperator",
-      "discriminator": [
-        185,
-        31,
-        8,
-        241,
-        50,
-        184,
-        102,
-        58
-      ],
-      "accounts": [
-        {
-          "name": "forum",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  102,
                   111,
+                  112,
+                  101,
                   114,
-                  117,
-                  109
+                  97,
+                  116,
+                  111,
+                  114
                 ]
               },
               {
-                "kind": "arg",
-                "path": "forumName"
+                "kind": "account",
+                "path": "operator.authority",
+                "account": "operator"
               }
             ]
           }
         },
         {
-          "name": "user",
+          "name": "operatorSession",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
                   115,
                   101,
-                  114
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "nftMint"
+                "path": "senderUser"
               }
             ]
           }
         },
         {
-          "name": "nftMint"
-        },
-        {
-          "name": "operator"
-        },
-        {
-          "name": "payer",
+          "name": "sender",
           "writable": true,
           "signer": true
         },
@@ -2018,20 +2509,41 @@ export type Neobots = {
         {
           "name": "forumName",
           "type": "string"
+        },
+        {
+          "name": "postSequence",
+          "type": "u32"
+        },
+        {
+          "name": "content",
+          "type": "string"
+        },
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "contentUri",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "unsetUserOperator",
+      "name": "rebindOwner",
       "discriminator": [
-        174,
-        26,
-        171,
-        98,
-        236,
-        133,
-        236,
-        69
+        248,
+        167,
+        239,
+        57,
+        203,
+        223,
+        44,
+        53
       ],
       "accounts": [
         {
@@ -2077,16 +2589,46 @@ export type Neobots = {
           }
         },
         {
-          "name": "nftMint"
+          "name": "nftMint",
+          "relations": [
+            "user"
+          ]
         },
         {
-          "name": "payer",
+          "name": "operatorSession",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "newOwner",
+          "signer": true
         }
       ],
       "args": [
@@ -2097,16 +2639,16 @@ export type Neobots = {
       ]
     },
     {
-      "name": "withdraw",
+      "name": "releaseVested",
       "discriminator": [
-        183,
-        18,
-        70,
-        156,
-        148,
-        109,
-        161,
-        34
+        81,
+        123,
+        176,
+        230,
+        16,
+        7,
+        162,
+        73
       ],
       "accounts": [
         {
@@ -2131,33 +2673,20 @@ export type Neobots = {
           }
         },
         {
-          "name": "mint",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  109,
-                  105,
-                  110,
-                  116
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "user",
+          "name": "vesting",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
+                  118,
                   101,
-                  114
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
                 ]
               },
               {
@@ -2168,38 +2697,13 @@ export type Neobots = {
           }
         },
         {
-          "name": "operator",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  111,
-                  112,
-                  101,
-                  114,
-                  97,
-                  116,
-                  111,
-                  114
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "operator"
-              }
-            ]
-          }
-        },
-        {
-          "name": "userTokenAccount",
+          "name": "vestingTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "vesting"
               },
               {
                 "kind": "account",
@@ -2250,45 +2754,24 @@ export type Neobots = {
           }
         },
         {
-          "name": "operatorSession",
+          "name": "nftMint",
+          "relations": [
+            "vesting"
+          ]
+        },
+        {
+          "name": "beneficiary",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  111,
-                  112,
-                  101,
-                  114,
-                  97,
-                  116,
-                  111,
-                  114,
-                  115,
-                  101,
-                  115,
-                  115,
-                  105,
-                  111,
-                  110
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "user"
-              }
-            ]
-          }
+          "signer": true
         },
         {
-          "name": "vaultTokenAccount",
+          "name": "userTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "beneficiary"
               },
               {
                 "kind": "account",
@@ -2339,14 +2822,22 @@ export type Neobots = {
           }
         },
         {
-          "name": "owner",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "nftMint",
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          },
           "relations": [
-            "user"
+            "forum"
           ]
         },
         {
@@ -2365,654 +2856,2664 @@ export type Neobots = {
         {
           "name": "forumName",
           "type": "string"
-        },
-        {
-          "name": "operator",
-          "type": "pubkey"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "baseAssetV1",
-      "discriminator": [
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0
-      ]
-    },
-    {
-      "name": "baseCollectionV1",
-      "discriminator": [
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0
-      ]
-    },
-    {
-      "name": "forum",
-      "discriminator": [
-        74,
-        10,
-        148,
-        158,
-        72,
-        60,
-        244,
-        226
-      ]
     },
     {
-      "name": "operator",
+      "name": "renounceSupplyIncrease",
       "discriminator": [
-        219,
-        31,
-        188,
+        242,
+        239,
+        141,
+        9,
         145,
-        69,
-        139,
-        204,
-        117
-      ]
-    },
-    {
-      "name": "operatorPool",
-      "discriminator": [
-        15,
-        224,
-        173,
-        204,
-        140,
-        63,
-        57,
-        189
+        81,
+        226,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        }
       ]
     },
     {
-      "name": "operatorSession",
+      "name": "resetUserActionPoints",
       "discriminator": [
+        65,
         202,
-        102,
-        82,
-        106,
-        135,
+        184,
         4,
-        204,
-        240
-      ]
-    },
-    {
-      "name": "post",
-      "discriminator": [
-        8,
-        147,
-        90,
-        186,
-        185,
-        56,
-        192,
-        150
-      ]
-    },
-    {
-      "name": "tag",
-      "discriminator": [
-        145,
-        209,
-        53,
-        147,
-        161,
-        98,
-        8,
-        114
-      ]
-    },
-    {
-      "name": "user",
-      "discriminator": [
-        159,
-        117,
-        95,
-        227,
-        239,
-        151,
-        58,
-        236
+        30,
+        134,
+        0,
+        181
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        }
       ]
     },
     {
-      "name": "userCounter",
+      "name": "setOperator",
       "discriminator": [
-        154,
-        114,
-        103,
-        93,
-        77,
-        57,
-        80,
-        227
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "nftNotVerified",
+        238,
+        153,
+        101,
+        169,
+        243,
+        131,
+        36,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operator",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "operator.authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nftMint",
+          "relations": [
+            "user"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        },
+        {
+          "name": "operator",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setUserOperator",
+      "discriminator": [
+        185,
+        31,
+        8,
+        241,
+        50,
+        184,
+        102,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "operator"
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "unsetUserOperator",
+      "discriminator": [
+        174,
+        26,
+        171,
+        98,
+        236,
+        133,
+        236,
+        69
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateRoundConfig",
+      "discriminator": [
+        217,
+        191,
+        114,
+        96,
+        111,
+        171,
+        37,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        },
+        {
+          "name": "roundConfig",
+          "type": {
+            "defined": {
+              "name": "roundConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateSupplyConfig",
+      "discriminator": [
+        71,
+        126,
+        113,
+        245,
+        125,
+        31,
+        28,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        },
+        {
+          "name": "maxSupply",
+          "type": "u64"
+        },
+        {
+          "name": "emissionSchedule",
+          "type": {
+            "defined": {
+              "name": "emissionSchedule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTokenMetadata",
+      "discriminator": [
+        243,
+        6,
+        8,
+        23,
+        126,
+        181,
+        251,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          },
+          "relations": [
+            "forum"
+          ]
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "makeImmutable",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateTokenMetadataToken2022",
+      "discriminator": [
+        209,
+        90,
+        253,
+        149,
+        187,
+        155,
+        248,
+        194
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          },
+          "relations": [
+            "forum"
+          ]
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "makeImmutable",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateUserProfile",
+      "discriminator": [
+        79,
+        75,
+        114,
+        130,
+        68,
+        123,
+        180,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint",
+          "relations": [
+            "user"
+          ]
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        },
+        {
+          "name": "personality",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "thumb",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "withdraw",
+      "discriminator": [
+        183,
+        18,
+        70,
+        156,
+        148,
+        109,
+        161,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "forum",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  117,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "forumName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operator",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "operator.authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "operatorSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "operatorSession"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "nftMint",
+          "relations": [
+            "user"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forumName",
+          "type": "string"
+        },
+        {
+          "name": "operator",
+          "type": "pubkey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "baseAssetV1",
+      "discriminator": [
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0
+      ]
+    },
+    {
+      "name": "baseCollectionV1",
+      "discriminator": [
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0
+      ]
+    },
+    {
+      "name": "forum",
+      "discriminator": [
+        74,
+        10,
+        148,
+        158,
+        72,
+        60,
+        244,
+        226
+      ]
+    },
+    {
+      "name": "operator",
+      "discriminator": [
+        219,
+        31,
+        188,
+        145,
+        69,
+        139,
+        204,
+        117
+      ]
+    },
+    {
+      "name": "operatorPool",
+      "discriminator": [
+        15,
+        224,
+        173,
+        204,
+        140,
+        63,
+        57,
+        189
+      ]
+    },
+    {
+      "name": "operatorSession",
+      "discriminator": [
+        202,
+        102,
+        82,
+        106,
+        135,
+        4,
+        204,
+        240
+      ]
+    },
+    {
+      "name": "post",
+      "discriminator": [
+        8,
+        147,
+        90,
+        186,
+        185,
+        56,
+        192,
+        150
+      ]
+    },
+    {
+      "name": "roundHistory",
+      "discriminator": [
+        213,
+        186,
+        30,
+        234,
+        13,
+        247,
+        252,
+        218
+      ]
+    },
+    {
+      "name": "tag",
+      "discriminator": [
+        145,
+        209,
+        53,
+        147,
+        161,
+        98,
+        8,
+        114
+      ]
+    },
+    {
+      "name": "user",
+      "discriminator": [
+        159,
+        117,
+        95,
+        227,
+        239,
+        151,
+        58,
+        236
+      ]
+    },
+    {
+      "name": "userCounter",
+      "discriminator": [
+        154,
+        114,
+        103,
+        93,
+        77,
+        57,
+        80,
+        227
+      ]
+    },
+    {
+      "name": "vesting",
+      "discriminator": [
+        100,
+        149,
+        66,
+        138,
+        95,
+        200,
+        128,
+        241
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accountMigrated",
+      "discriminator": [
+        153,
+        121,
+        252,
+        128,
+        30,
+        241,
+        166,
+        101
+      ]
+    },
+    {
+      "name": "claimed",
+      "discriminator": [
+        217,
+        192,
+        123,
+        72,
+        108,
+        150,
+        248,
+        33
+      ]
+    },
+    {
+      "name": "commentAdded",
+      "discriminator": [
+        18,
+        240,
+        225,
+        131,
+        42,
+        132,
+        33,
+        44
+      ]
+    },
+    {
+      "name": "delegationCleared",
+      "discriminator": [
+        132,
+        72,
+        180,
+        252,
+        247,
+        246,
+        1,
+        253
+      ]
+    },
+    {
+      "name": "deposited",
+      "discriminator": [
+        111,
+        141,
+        26,
+        45,
+        161,
+        35,
+        100,
+        57
+      ]
+    },
+    {
+      "name": "operatorCharged",
+      "discriminator": [
+        179,
+        0,
+        20,
+        184,
+        183,
+        55,
+        176,
+        223
+      ]
+    },
+    {
+      "name": "ownerRebound",
+      "discriminator": [
+        135,
+        62,
+        66,
+        15,
+        93,
+        75,
+        138,
+        171
+      ]
+    },
+    {
+      "name": "postCreated",
+      "discriminator": [
+        209,
+        178,
+        232,
+        24,
+        158,
+        92,
+        77,
+        227
+      ]
+    },
+    {
+      "name": "reactionAdded",
+      "discriminator": [
+        149,
+        40,
+        209,
+        40,
+        223,
+        146,
+        198,
+        29
+      ]
+    },
+    {
+      "name": "rewardDistributed",
+      "discriminator": [
+        36,
+        65,
+        223,
+        38,
+        136,
+        162,
+        10,
+        30
+      ]
+    },
+    {
+      "name": "rewardsForfeited",
+      "discriminator": [
+        171,
+        190,
+        87,
+        208,
+        87,
+        188,
+        100,
+        177
+      ]
+    },
+    {
+      "name": "roundAdvanced",
+      "discriminator": [
+        215,
+        177,
+        143,
+        241,
+        202,
+        145,
+        181,
+        213
+      ]
+    },
+    {
+      "name": "userClosed",
+      "discriminator": [
+        78,
+        205,
+        4,
+        245,
+        226,
+        24,
+        219,
+        51
+      ]
+    },
+    {
+      "name": "vestingReleased",
+      "discriminator": [
+        6,
+        77,
+        66,
+        76,
+        214,
+        185,
+        161,
+        191
+      ]
+    },
+    {
+      "name": "withdrawn",
+      "discriminator": [
+        20,
+        89,
+        223,
+        198,
+        194,
+        124,
+        219,
+        13
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "nftNotVerified",
       "msg": "NFT is not verified"
     },
     {
-      "code": 6001,
-      "name": "nftNotOwned",
-      "msg": "Does not own the NFT"
+      "code": 6001,
+      "name": "nftNotOwned",
+      "msg": "Does not own the NFT"
+    },
+    {
+      "code": 6002,
+      "name": "notEnoughActionPoints",
+      "msg": "Insufficient Action Points"
+    },
+    {
+      "code": 6003,
+      "name": "claimExceedBalance",
+      "msg": "Claim amount exceeds claimable_balance"
+    },
+    {
+      "code": 6004,
+      "name": "tooEarlyToAdvanceRound",
+      "msg": "Cannot advance round yet (too early)"
+    },
+    {
+      "code": 6005,
+      "name": "userIsBanned",
+      "msg": "User is banned from performing this action"
+    },
+    {
+      "code": 6006,
+      "name": "notEnoughClaimableAmount",
+      "msg": "Not enough claimable amount"
+    },
+    {
+      "code": 6007,
+      "name": "invalidNftOwnership",
+      "msg": "This NFT is not owned by the signer or does not have the correct amount"
+    },
+    {
+      "code": 6008,
+      "name": "mathOverflow",
+      "msg": "Overflow or invalid math operation"
+    },
+    {
+      "code": 6009,
+      "name": "exceedMaxRepeatCount",
+      "msg": "Reaction or Quote count limit exceeded (max 30)"
+    },
+    {
+      "code": 6010,
+      "name": "accessDenied",
+      "msg": "Access denied: not an admin"
+    },
+    {
+      "code": 6011,
+      "name": "invalidForumName",
+      "msg": "Invalid forum name"
+    },
+    {
+      "code": 6012,
+      "name": "invalidMint",
+      "msg": "Invalid mint"
+    },
+    {
+      "code": 6013,
+      "name": "invalidInput",
+      "msg": "Invalid input"
+    },
+    {
+      "code": 6014,
+      "name": "insufficientFunds",
+      "msg": "Insufficient funds"
+    },
+    {
+      "code": 6015,
+      "name": "operatorSessionNotInitialized",
+      "msg": "Operator session not initialized"
+    },
+    {
+      "code": 6016,
+      "name": "operatorSessionAlreadyInitialized",
+      "msg": "Operator session already initialized"
+    },
+    {
+      "code": 6017,
+      "name": "operatorKeyMismatch",
+      "msg": "Operator key does not match with operator session"
+    },
+    {
+      "code": 6018,
+      "name": "nothingToRelease",
+      "msg": "No vested amount to release"
+    },
+    {
+      "code": 6019,
+      "name": "invalidRoundConfig",
+      "msg": "Invalid round config"
+    },
+    {
+      "code": 6020,
+      "name": "roundBudgetExhausted",
+      "msg": "Distribution budget of this round is exhausted"
+    },
+    {
+      "code": 6021,
+      "name": "maxSupplyReached",
+      "msg": "Max supply of the token is reached"
+    },
+    {
+      "code": 6022,
+      "name": "maxSupplyLocked",
+      "msg": "Max supply is locked and cannot be increased"
+    },
+    {
+      "code": 6023,
+      "name": "invalidSupplyConfig",
+      "msg": "Invalid supply config"
+    },
+    {
+      "code": 6024,
+      "name": "invalidUserProfile",
+      "msg": "User profile field is too long or contains invalid characters"
+    },
+    {
+      "code": 6025,
+      "name": "invalidContentUri",
+      "msg": "Content URI is too long or contains invalid characters"
+    },
+    {
+      "code": 6026,
+      "name": "ownerMismatch",
+      "msg": "NFT owner changed since the user was bound, the owner must rebind first"
+    },
+    {
+      "code": 6027,
+      "name": "operatorSessionNotEmpty",
+      "msg": "Operator session still holds funds, withdraw them first"
+    },
+    {
+      "code": 6028,
+      "name": "roundAlreadyAdvanced",
+      "msg": "Round was already advanced in this slot"
+    },
+    {
+      "code": 6029,
+      "name": "invalidAccountType",
+      "msg": "Account is not a migratable account of this program"
+    },
+    {
+      "code": 6030,
+      "name": "accountUpToDate",
+      "msg": "Account already has the current layout"
+    },
+    {
+      "code": 6031,
+      "name": "unclaimedRewards",
+      "msg": "User still has claimable rewards, claim them first"
+    },
+    {
+      "code": 6032,
+      "name": "vestingAccountsMissing",
+      "msg": "Claims vest, the vesting accounts are required"
+    }
+  ],
+  "types": [
+    {
+      "name": "accountMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "actionPoints",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "post",
+            "type": "u64"
+          },
+          {
+            "name": "comment",
+            "type": "u64"
+          },
+          {
+            "name": "upvote",
+            "type": "u64"
+          },
+          {
+            "name": "downvote",
+            "type": "u64"
+          },
+          {
+            "name": "like",
+            "type": "u64"
+          },
+          {
+            "name": "banvote",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "baseAssetV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": {
+              "defined": {
+                "name": "key"
+              }
+            }
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "updateAuthority",
+            "type": {
+              "defined": {
+                "name": "updateAuthority"
+              }
+            }
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "seq",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "notEnoughActionPoints",
-      "msg": "Insufficient Action Points"
+      "name": "baseCollectionV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": {
+              "defined": {
+                "name": "key"
+              }
+            }
+          },
+          {
+            "name": "updateAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "numMinted",
+            "type": "u32"
+          },
+          {
+            "name": "currentSize",
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "claimExceedBalance",
-      "msg": "Claim amount exceeds claimable_balance"
+      "name": "claimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "vested",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "tooEarlyToAdvanceRound",
-      "msg": "Cannot advance round yet (too early)"
+      "name": "commentAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "pubkey"
+          },
+          {
+            "name": "post",
+            "type": "pubkey"
+          },
+          {
+            "name": "postAuthor",
+            "type": "pubkey"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "sequence",
+            "type": "u32"
+          },
+          {
+            "name": "content",
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "contentUri",
+            "type": "string"
+          },
+          {
+            "name": "operator",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "userIsBanned",
-      "msg": "User is banned from performing this action"
+      "name": "delegationCleared",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "nftOwner",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "notEnoughClaimableAmount",
-      "msg": "Not enough claimable amount"
+      "name": "deposited",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "operatorSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "invalidNftOwnership",
-      "msg": "This NFT is not owned by the signer or does not have the correct amount"
+      "name": "durationCurve",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "linear"
+          },
+          {
+            "name": "log"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "mathOverflow",
-      "msg": "Overflow or invalid math operation"
+      "name": "emissionSchedule",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "constant"
+          },
+          {
+            "name": "halving",
+            "fields": [
+              {
+                "name": "intervalRounds",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "decay",
+            "fields": [
+              {
+                "name": "rate",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "forum",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "roundDistributed",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "nftCollection",
+            "type": "pubkey"
+          },
+          {
+            "name": "roundStatus",
+            "type": {
+              "defined": {
+                "name": "roundStatus"
+              }
+            }
+          },
+          {
+            "name": "roundConfig",
+            "type": {
+              "defined": {
+                "name": "roundConfig"
+              }
+            }
+          },
+          {
+            "name": "nextRoundConfig",
+            "type": {
+              "defined": {
+                "name": "roundConfig"
+              }
+            }
+          },
+          {
+            "name": "supplyConfig",
+            "type": {
+              "defined": {
+                "name": "supplyConfig"
+              }
+            }
+          },
+          {
+            "name": "roundActivity",
+            "type": {
+              "defined": {
+                "name": "roundActivity"
+              }
+            }
+          },
+          {
+            "name": "lastRoundActivity",
+            "type": {
+              "defined": {
+                "name": "roundActivity"
+              }
+            }
+          },
+          {
+            "name": "lastAdvanceSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "exceedMaxRepeatCount",
-      "msg": "Reaction or Quote count limit exceeded (max 30)"
+      "name": "interactionMetricEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "shortUserId",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "accessDenied",
-      "msg": "Access denied: not an admin"
+      "name": "key",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "uninitialized"
+          },
+          {
+            "name": "assetV1"
+          },
+          {
+            "name": "hashedAssetV1"
+          },
+          {
+            "name": "pluginHeaderV1"
+          },
+          {
+            "name": "pluginRegistryV1"
+          },
+          {
+            "name": "collectionV1"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "invalidForumName",
-      "msg": "Invalid forum name"
+      "name": "operator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": {
+                "name": "operatorPrice"
+              }
+            }
+          },
+          {
+            "name": "nextRoundPrice",
+            "type": {
+              "defined": {
+                "name": "operatorPrice"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "invalidMint",
-      "msg": "Invalid mint"
+      "name": "operatorCharged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "invalidInput",
-      "msg": "Invalid input"
+      "name": "operatorPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "insufficientFunds",
-      "msg": "Insufficient funds"
+      "name": "operatorPrice",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pricePerPost",
+            "type": "u64"
+          },
+          {
+            "name": "pricePerComment",
+            "type": "u64"
+          },
+          {
+            "name": "pricePerLike",
+            "type": "u64"
+          },
+          {
+            "name": "pricePerVote",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "operatorSessionNotInitialized",
-      "msg": "Operator session not initialized"
+      "name": "operatorSession",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "amountForUser",
+            "type": "u64"
+          },
+          {
+            "name": "amountForOperator",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "operatorSessionAlreadyInitialized",
-      "msg": "Operator session already initialized"
+      "name": "ownerRebound",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "operatorKeyMismatch",
-      "msg": "Operator key does not match with operator session"
-    }
-  ],
-  "types": [
-    {
-      "name": "actionPoints",
+      "name": "post",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "post",
-            "type": "u64"
+            "name": "author",
+            "type": "pubkey"
           },
           {
-            "name": "comment",
-            "type": "u64"
+            "name": "createdAt",
+            "type": "i64"
           },
           {
-            "name": "upvote",
-            "type": "u64"
+            "name": "sequence",
+            "type": "u32"
           },
           {
-            "name": "downvote",
-            "type": "u64"
+            "name": "interactable",
+            "type": "bool"
           },
           {
-            "name": "like",
-            "type": "u64"
+            "name": "content",
+            "type": "string"
           },
           {
-            "name": "banvote",
-            "type": "u64"
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "contentUri",
+            "type": "string"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "baseAssetV1",
+      "name": "postCreated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "key",
-            "type": {
-              "defined": {
-                "name": "key"
-              }
-            }
+            "name": "forum",
+            "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "post",
             "type": "pubkey"
           },
           {
-            "name": "updateAuthority",
-            "type": {
-              "defined": {
-                "name": "updateAuthority"
-              }
-            }
+            "name": "author",
+            "type": "pubkey"
           },
           {
-            "name": "name",
-            "type": "string"
+            "name": "sequence",
+            "type": "u32"
           },
           {
-            "name": "uri",
+            "name": "content",
             "type": "string"
           },
           {
-            "name": "seq",
+            "name": "contentHash",
             "type": {
-              "option": "u64"
+              "array": [
+                "u8",
+                32
+              ]
             }
+          },
+          {
+            "name": "contentUri",
+            "type": "string"
           }
         ]
       }
     },
     {
-      "name": "baseCollectionV1",
+      "name": "reactionAdded",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "key",
-            "type": {
-              "defined": {
-                "name": "key"
-              }
-            }
+            "name": "forum",
+            "type": "pubkey"
           },
           {
-            "name": "updateAuthority",
+            "name": "post",
             "type": "pubkey"
           },
           {
-            "name": "name",
-            "type": "string"
+            "name": "commentAuthor",
+            "type": "pubkey"
           },
           {
-            "name": "uri",
-            "type": "string"
+            "name": "sender",
+            "type": "pubkey"
           },
           {
-            "name": "numMinted",
+            "name": "sequence",
             "type": "u32"
           },
           {
-            "name": "currentSize",
+            "name": "commentSequence",
             "type": "u32"
+          },
+          {
+            "name": "reactionType",
+            "type": {
+              "defined": {
+                "name": "reactionType"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "forum",
+      "name": "reactionType",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "admin",
-            "type": "pubkey"
+            "name": "upvote"
           },
           {
-            "name": "roundDistributed",
-            "type": "u64"
+            "name": "downvote"
           },
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "like"
           },
           {
-            "name": "nftCollection",
-            "type": "pubkey"
-          },
+            "name": "banvote"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "roundStatus",
-            "type": {
-              "defined": {
-                "name": "roundStatus"
-              }
-            }
+            "name": "roundNumber",
+            "type": "u64"
           },
           {
-            "name": "roundConfig",
-            "type": {
-              "defined": {
-                "name": "roundConfig"
-              }
-            }
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardDistributed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
           },
           {
-            "name": "nextRoundConfig",
-            "type": {
-              "defined": {
-                "name": "roundConfig"
-              }
-            }
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "reason",
+            "type": "string"
           }
         ]
       }
     },
     {
-      "name": "interactionMetricEntry",
+      "name": "rewardsForfeited",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "shortUserId",
-            "type": {
-              "array": [
-                "u8",
-                6
-              ]
-            }
+            "name": "user",
+            "type": "pubkey"
           },
           {
-            "name": "count",
-            "type": "u8"
+            "name": "amount",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "key",
+      "name": "roundActivity",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "uninitialized"
+            "name": "activeUsers",
+            "type": "u64"
           },
           {
-            "name": "assetV1"
+            "name": "postCount",
+            "type": "u64"
           },
           {
-            "name": "hashedAssetV1"
+            "name": "commentCount",
+            "type": "u64"
           },
           {
-            "name": "pluginHeaderV1"
+            "name": "upvoteCount",
+            "type": "u64"
           },
           {
-            "name": "pluginRegistryV1"
+            "name": "downvoteCount",
+            "type": "u64"
           },
           {
-            "name": "collectionV1"
+            "name": "likeCount",
+            "type": "u64"
+          },
+          {
+            "name": "banvoteCount",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "operator",
+      "name": "roundAdvanced",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "forum",
             "type": "pubkey"
           },
           {
-            "name": "price",
-            "type": {
-              "defined": {
-                "name": "operatorPrice"
-              }
-            }
+            "name": "roundNumber",
+            "type": "u64"
           },
           {
-            "name": "nextRoundPrice",
+            "name": "roundStartTime",
+            "type": "i64"
+          },
+          {
+            "name": "roundDuration",
+            "type": "u64"
+          },
+          {
+            "name": "roundMaxDistribution",
+            "type": "u64"
+          },
+          {
+            "name": "previousRoundDistributed",
+            "type": "u64"
+          },
+          {
+            "name": "previousRoundActivity",
             "type": {
               "defined": {
-                "name": "operatorPrice"
+                "name": "roundActivity"
               }
             }
           },
           {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "operatorPool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
+            "name": "crank",
             "type": "pubkey"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "crankReward",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "operatorPrice",
+      "name": "roundConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pricePerPost",
+            "name": "roundDuration",
+            "type": "u64"
+          },
+          {
+            "name": "roundMinDistributionRate",
+            "type": "u64"
+          },
+          {
+            "name": "roundMaxDistributionRate",
+            "type": "u64"
+          },
+          {
+            "name": "kCommentReceiver",
+            "type": "u64"
+          },
+          {
+            "name": "kComment",
             "type": "u64"
           },
           {
-            "name": "pricePerComment",
+            "name": "kQuote",
             "type": "u64"
           },
           {
-            "name": "pricePerLike",
+            "name": "kReactionGiver",
             "type": "u64"
           },
           {
-            "name": "pricePerVote",
+            "name": "kReactionReceiver",
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "operatorSession",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "user",
-            "type": "pubkey"
+            "name": "decayFactor",
+            "type": "u64"
           },
           {
-            "name": "operator",
-            "type": "pubkey"
+            "name": "roundDurationMode",
+            "type": {
+              "defined": {
+                "name": "roundDurationMode"
+              }
+            }
           },
           {
-            "name": "vault",
-            "type": "pubkey"
+            "name": "defaultActionPoints",
+            "type": {
+              "defined": {
+                "name": "actionPoints"
+              }
+            }
           },
           {
-            "name": "amountForUser",
+            "name": "vestingDuration",
             "type": "u64"
           },
           {
-            "name": "amountForOperator",
+            "name": "claimWindowRounds",
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "crankReward",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "post",
+      "name": "roundDurationMode",
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "author",
-            "type": "pubkey"
-          },
-          {
-            "name": "createdAt",
-            "type": "i64"
-          },
-          {
-            "name": "sequence",
-            "type": "u32"
-          },
-          {
-            "name": "interactable",
-            "type": "bool"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "content",
-            "type": "string"
+            "name": "fixed"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "adaptive",
+            "fields": [
+              {
+                "name": "minDuration",
+                "type": "u64"
+              },
+              {
+                "name": "maxDuration",
+                "type": "u64"
+              },
+              {
+                "name": "usersAtMax",
+                "type": "u64"
+              },
+              {
+                "name": "curve",
+                "type": {
+                  "defined": {
+                    "name": "durationCurve"
+                  }
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "reactionType",
+      "name": "roundHistory",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "upvote"
+            "name": "forum",
+            "type": "pubkey"
           },
           {
-            "name": "downvote"
+            "name": "nextIndex",
+            "type": "u32"
           },
           {
-            "name": "like"
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "roundHistoryEntry"
+                }
+              }
+            }
           },
           {
-            "name": "banvote"
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "roundConfig",
+      "name": "roundHistoryEntry",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "roundDuration",
-            "type": "u64"
-          },
-          {
-            "name": "roundMinDistributionRate",
-            "type": "u64"
-          },
-          {
-            "name": "roundMaxDistributionRate",
-            "type": "u64"
-          },
-          {
-            "name": "kCommentReceiver",
+            "name": "roundNumber",
             "type": "u64"
           },
           {
-            "name": "kComment",
-            "type": "u64"
+            "name": "roundStartTime",
+            "type": "i64"
           },
           {
-            "name": "kQuote",
-            "type": "u64"
+            "name": "roundEndTime",
+            "type": "i64"
           },
           {
-            "name": "kReactionGiver",
+            "name": "roundDistributionRate",
             "type": "u64"
           },
           {
-            "name": "kReactionReceiver",
+            "name": "roundMaxDistribution",
             "type": "u64"
           },
           {
-            "name": "decayFactor",
+            "name": "roundDistributed",
             "type": "u64"
           },
           {
-            "name": "defaultActionPoints",
+            "name": "activity",
             "type": {
               "defined": {
-                "name": "actionPoints"
+                "name": "roundActivity"
               }
             }
           }
@@ -3039,6 +5540,38 @@ export type Neobots = {
           {
             "name": "roundDistributionRate",
             "type": "u64"
+          },
+          {
+            "name": "roundDuration",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "supplyConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          },
+          {
+            "name": "maxSupplyLocked",
+            "type": "bool"
+          },
+          {
+            "name": "initialRoundEmission",
+            "type": "u64"
+          },
+          {
+            "name": "emissionSchedule",
+            "type": {
+              "defined": {
+                "name": "emissionSchedule"
+              }
+            }
           }
         ]
       }
@@ -3082,6 +5615,10 @@ export type Neobots = {
             "name": "nftMint",
             "type": "pubkey"
           },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
           {
             "name": "claimableAmount",
             "type": "u64"
@@ -3090,6 +5627,22 @@ export type Neobots = {
             "name": "localRoundNumber",
             "type": "u64"
           },
+          {
+            "name": "lastActiveRound",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "rewardBuckets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "rewardBucket"
+                }
+              }
+            }
+          },
           {
             "name": "actionPoints",
             "type": {
@@ -3181,6 +5734,22 @@ export type Neobots = {
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
           }
         ]
       }
@@ -3197,6 +5766,86 @@ export type Neobots = {
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vesting",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "duration",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingReleased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "operatorSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
           }
         ]
       }
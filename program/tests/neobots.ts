@@ -22,12 +22,17 @@ import { expect } from "chai";
 import { SYSTEM_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/native/system";
 import { publicKey } from "@coral-xyz/anchor/dist/cjs/utils";
 import { CoreNFTBuilder } from "./CoreNFTBuilder";
+import { createHash } from "crypto";
 
 // pub const TOKEN_UNIT: u64 = 1_000_000_000;
 // pub const RATIO_SCALE: u64 = 1_000_000;
 const TOKEN_UNIT = 1_000_000_000;
 const RATIO_SCALE = 1_000_000;
 
+// sha256 commitment of a body kept in the content store
+const contentHash = (body: string) =>
+  Array.from(createHash("sha256").update(body).digest());
+
 describe("neobots", async () => {
   // Configure the client to use the local cluster.
   // anchor
@@ -166,7 +171,7 @@ describe("neobots", async () => {
   it("initialize forum", async () => {
     // Add your test here.
     const tx = await program.methods
-      .initializeForum("forum_id")
+      .initializeForum("forum_id", null, null)
       .accounts({
         payer: provider.wallet.publicKey,
         nftCollection: collection.publicKey,
@@ -220,7 +225,13 @@ describe("neobots", async () => {
 
   it("create post", async () => {
     const tx = await program.methods
-      .createPost("forum_id", "Hello, world!", "tag_name")
+      .createPost(
+        "forum_id",
+        "Hello, world!",
+        "tag_name",
+        contentHash("Hello, world!"),
+        "post-key"
+      )
       .accounts({
         owner: user1.publicKey,
         nftMint: nft1.publicKey,
@@ -264,7 +275,13 @@ describe("neobots", async () => {
 
   it("Add comment", async () => {
     const tx = await program.methods
-      .addComment("forum_id", 0, "Hello, world!")
+      .addComment(
+        "forum_id",
+        0,
+        "Hello, world!",
+        contentHash("Hello, world!"),
+        "comment-key"
+      )
       .accounts({
         postAuthor: user1Pda,
         senderNftMint: nft2.publicKey,
@@ -376,6 +393,9 @@ describe("neobots", async () => {
       .advanceRound("forum_id")
       .accounts({
         signer: user1.publicKey,
+        mint: splTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .signers([user1])
       .rpc();
@@ -394,7 +414,13 @@ describe("neobots", async () => {
     it("[operator] create post should fail nft3 signed by user2", async () => {
     try{
     const tx = await program.methods
-      .createPost("forum_id", "Hello, world!", "tag_name")
+      .createPost(
+        "forum_id",
+        "Hello, world!",
+        "tag_name",
+        contentHash("Hello, world!"),
+        "post-key"
+      )
       .accounts({
         owner: user2.publicKey,
         nftMint: nft3.publicKey,
@@ -428,7 +454,13 @@ describe("neobots", async () => {
 
   it("[operator] create post should not fail signed by user2", async () => {
     const tx = await program.methods
-      .createPost("forum_id", "Hello, world!", "tag_name")
+      .createPost(
+        "forum_id",
+        "Hello, world!",
+        "tag_name",
+        contentHash("Hello, world!"),
+        "post-key"
+      )
       .accounts({
         owner: user2.publicKey,
         nftMint: nft3.publicKey,
@@ -453,7 +485,13 @@ describe("neobots", async () => {
   it("[operator] create post should fail nft3 signed by user2 after unset operator", async () => {
     try{
     const tx = await program.methods
-      .createPost("forum_id", "Hello, world!", "tag_name")
+      .createPost(
+        "forum_id",
+        "Hello, world!",
+        "tag_name",
+        contentHash("Hello, world!"),
+        "post-key"
+      )
       .accounts({
         owner: user2.publicKey,
         nftMint: nft3.publicKey,
@@ -4,6 +4,7 @@ import {
   BN,
   Program,
   BorshInstructionCoder,
+  IdlTypes,
 } from "@coral-xyz/anchor";
 import idl from "../program/target/idl/neobots.json";
 import type { Neobots } from "../program/target/types/neobots";
@@ -40,6 +41,15 @@ export interface ActionPoints {
   banvote: number;
 }
 
+// sha256 commitment of a body kept in the content store
+async function contentHash(body: string): Promise<number[]> {
+  const digest = await globalThis.crypto.subtle.digest(
+    "SHA-256",
+    new TextEncoder().encode(body)
+  );
+  return Array.from(new Uint8Array(digest));
+}
+
 export class ProgramService {
   private readonly forumId = "forum_id";
 
@@ -144,8 +154,12 @@ export class ProgramService {
     );
   }
 
+  // the program starts the forum with the parameters of its build profile
+  // when no round config or first round status is given
   async initializeForum(
-    nftCollection: PublicKey
+    nftCollection: PublicKey,
+    roundConfig: IdlTypes<Neobots>["roundConfig"] | null = null,
+    roundStatus: IdlTypes<Neobots>["roundStatus"] | null = null
   ): Promise<TransactionSignature> {
     const METADATA_SEED = "metadata";
     const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
@@ -167,7 +181,7 @@ export class ProgramService {
     );
 
     return await this.program.methods
-      .initializeForum(this.forumId)
+      .initializeForum(this.forumId, roundConfig, roundStatus)
       .accounts({
         payer: this.anchorProvider.wallet.publicKey,
         nftCollection: nftCollection,
@@ -328,14 +342,21 @@ export class ProgramService {
   async createPost(
     userNftMint: PublicKey,
     content: string,
-    tag_name: string
+    tag_name: string,
+    body: string = content
   ): Promise<TransactionSignature> {
     const user = await this.getUser(userNftMint);
     if (user == null) {
       console.log("user not found");
     }
     return await this.program.methods
-      .createPost(this.forumId, content, tag_name)
+      .createPost(
+        this.forumId,
+        content,
+        tag_name,
+        await contentHash(body),
+        content
+      )
       .accounts({
         owner: this.anchorProvider.wallet.publicKey,
         nftMint: userNftMint,
@@ -348,10 +369,17 @@ export class ProgramService {
     userNftMint: PublicKey,
     postId: number,
     postAuthor: PublicKey,
-    content: string
+    content: string,
+    body: string = content
   ): Promise<TransactionSignature> {
     return await this.program.methods
-      .addComment(this.forumId, postId, content)
+      .addComment(
+        this.forumId,
+        postId,
+        content,
+        await contentHash(body),
+        content
+      )
       .accounts({
         postAuthor: postAuthor,
         senderNftMint: userNftMint,
@@ -418,9 +446,13 @@ export class ProgramService {
   }
 
   async advanceRound(): Promise<TransactionSignature> {
+    const forum = await this.getForum();
     return await this.program.methods
       .advanceRound(this.forumId)
       .accounts({
+        mint: forum.mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
         systemProgram: SYSTEM_PROGRAM_ID,
       } as any)
       .signers([])
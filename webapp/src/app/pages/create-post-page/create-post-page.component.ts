@@ -118,18 +118,19 @@ export class CreatePostPageComponent {
     if (this.posted) return;
 
     const nft = (await this.nftService.getOwnedNfts())[0]!;
+    const body = JSON.stringify({
+      title: this.title,
+      content: this.content,
+      enableVoting: this.voting,
+      votingTitle: this.votingTitle,
+      votingOptions: this.votingOptions.split(','),
+    });
+    // the post commits to the hash of the stored body, not of its key
     const sig = await this.program.createPost(
       new PublicKey(nft.publicKey),
-      await this.putOffchainData(
-        JSON.stringify({
-          title: this.title,
-          content: this.content,
-          enableVoting: this.voting,
-          votingTitle: this.votingTitle,
-          votingOptions: this.votingOptions.split(','),
-        })
-      ),
-      'default'
+      await this.putOffchainData(body),
+      'default',
+      body
     );
     this.posted = true;
 